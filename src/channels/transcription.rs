@@ -31,6 +31,20 @@ fn normalize_audio_filename(file_name: &str) -> String {
     }
 }
 
+/// Total request timeout for an upload of `len` bytes.
+///
+/// Larger files legitimately take longer to upload and transcribe, so the
+/// budget grows with size instead of using one fixed value.
+fn request_timeout(len: usize, config: &TranscriptionConfig) -> std::time::Duration {
+    let mib = (len as u64).div_ceil(1024 * 1024);
+    std::time::Duration::from_secs(
+        config
+            .request_timeout_secs
+            .saturating_add(mib.saturating_mul(config.request_timeout_secs_per_mib))
+            .min(config.max_request_timeout_secs),
+    )
+}
+
 /// Transcribe audio bytes via a Whisper-compatible transcription API.
 ///
 /// Returns the transcribed text on success.  Requires `GROQ_API_KEY` in the
@@ -63,7 +77,12 @@ pub async fn transcribe_audio(
         "GROQ_API_KEY environment variable is not set — required for voice transcription",
     )?;
 
-    let client = crate::config::build_runtime_proxy_client("transcription.groq");
+    let client = crate::config::build_runtime_proxy_client_with_timeouts(
+        "transcription.groq",
+        config.max_request_timeout_secs,
+        config.connect_timeout_secs,
+    );
+    let timeout = request_timeout(audio_data.len(), config);
 
     let file_part = Part::bytes(audio_data)
         .file_name(normalized_name)
@@ -81,6 +100,7 @@ pub async fn transcribe_audio(
     let resp = client
         .post(&config.api_url)
         .bearer_auth(&api_key)
+        .timeout(timeout)
         .multipart(form)
         .send()
         .await
@@ -179,6 +199,24 @@ mod tests {
         assert_eq!(mime_for_audio(""), None);
     }
 
+    #[test]
+    fn request_timeout_scales_with_size_and_is_capped() {
+        let config = TranscriptionConfig::default();
+        assert_eq!(request_timeout(0, &config).as_secs(), 30);
+        assert_eq!(request_timeout(1, &config).as_secs(), 42);
+        assert_eq!(request_timeout(5 * 1024 * 1024, &config).as_secs(), 90);
+        assert_eq!(request_timeout(MAX_AUDIO_BYTES, &config).as_secs(), 300);
+
+        let config = TranscriptionConfig {
+            request_timeout_secs: 5,
+            request_timeout_secs_per_mib: 1,
+            max_request_timeout_secs: 20,
+            ..TranscriptionConfig::default()
+        };
+        assert_eq!(request_timeout(3 * 1024 * 1024, &config).as_secs(), 8);
+        assert_eq!(request_timeout(MAX_AUDIO_BYTES, &config).as_secs(), 20);
+    }
+
     #[test]
     fn normalize_audio_filename_rewrites_oga() {
         assert_eq!(normalize_audio_filename("voice.oga"), "voice.ogg");
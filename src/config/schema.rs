@@ -489,6 +489,22 @@ fn default_transcription_max_duration_secs() -> u64 {
     120
 }
 
+fn default_transcription_connect_timeout_secs() -> u64 {
+    10
+}
+
+fn default_transcription_request_timeout_secs() -> u64 {
+    30
+}
+
+fn default_transcription_request_timeout_secs_per_mib() -> u64 {
+    12
+}
+
+fn default_transcription_max_request_timeout_secs() -> u64 {
+    300
+}
+
 /// Voice transcription configuration (Whisper API via Groq).
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TranscriptionConfig {
@@ -507,6 +523,18 @@ pub struct TranscriptionConfig {
     /// Maximum voice duration in seconds (messages longer than this are skipped).
     #[serde(default = "default_transcription_max_duration_secs")]
     pub max_duration_secs: u64,
+    /// Connect timeout for the transcription API, in seconds.
+    #[serde(default = "default_transcription_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Request timeout for the smallest uploads, in seconds.
+    #[serde(default = "default_transcription_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// Extra request time allowed per MiB of audio, in seconds.
+    #[serde(default = "default_transcription_request_timeout_secs_per_mib")]
+    pub request_timeout_secs_per_mib: u64,
+    /// Upper bound on the size-scaled request timeout, in seconds.
+    #[serde(default = "default_transcription_max_request_timeout_secs")]
+    pub max_request_timeout_secs: u64,
 }
 
 impl Default for TranscriptionConfig {
@@ -517,6 +545,10 @@ impl Default for TranscriptionConfig {
             model: default_transcription_model(),
             language: None,
             max_duration_secs: default_transcription_max_duration_secs(),
+            connect_timeout_secs: default_transcription_connect_timeout_secs(),
+            request_timeout_secs: default_transcription_request_timeout_secs(),
+            request_timeout_secs_per_mib: default_transcription_request_timeout_secs_per_mib(),
+            max_request_timeout_secs: default_transcription_max_request_timeout_secs(),
         }
     }
 }
@@ -641,6 +673,10 @@ pub struct AgentConfig {
     /// Tool dispatch strategy (e.g. `"auto"`). Default: `"auto"`.
     #[serde(default = "default_agent_tool_dispatcher")]
     pub tool_dispatcher: String,
+    /// Default arguments per tool name (`[agent.tool_defaults.<tool>]`).
+    /// Merged under each call's arguments; arguments supplied by the model win on conflict.
+    #[serde(default)]
+    pub tool_defaults: HashMap<String, serde_json::Value>,
 }
 
 fn default_agent_max_tool_iterations() -> usize {
@@ -663,6 +699,7 @@ impl Default for AgentConfig {
             max_history_messages: default_agent_max_history_messages(),
             parallel_tools: false,
             tool_dispatcher: default_agent_tool_dispatcher(),
+            tool_defaults: HashMap::new(),
         }
     }
 }
@@ -978,6 +1015,7 @@ impl Default for PeripheralBoardConfig {
 /// Gateway server configuration (`[gateway]` section).
 ///
 /// Controls the HTTP gateway for webhook and pairing endpoints.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct GatewayConfig {
     /// Gateway port (default: 42617)
@@ -1024,6 +1062,15 @@ pub struct GatewayConfig {
     /// Node-control protocol scaffold (`[gateway.node_control]`).
     #[serde(default)]
     pub node_control: NodeControlConfig,
+
+    /// Expose `POST /api/tools/{name}` for direct tool execution (default: false).
+    /// Tools in `autonomy.non_cli_excluded_tools` are still refused.
+    #[serde(default)]
+    pub tool_execution_enabled: bool,
+
+    /// Max `POST /api/tools/{name}` executions running at once (default: 4).
+    #[serde(default = "default_tool_execution_max_concurrent")]
+    pub tool_execution_max_concurrent: usize,
 }
 
 /// Node-control scaffold settings under `[gateway.node_control]`.
@@ -1072,6 +1119,10 @@ fn default_gateway_idempotency_max_keys() -> usize {
     10_000
 }
 
+fn default_tool_execution_max_concurrent() -> usize {
+    4
+}
+
 fn default_true() -> bool {
     true
 }
@@ -1091,6 +1142,8 @@ impl Default for GatewayConfig {
             idempotency_ttl_secs: default_idempotency_ttl_secs(),
             idempotency_max_keys: default_gateway_idempotency_max_keys(),
             node_control: NodeControlConfig::default(),
+            tool_execution_enabled: false,
+            tool_execution_max_concurrent: default_tool_execution_max_concurrent(),
         }
     }
 }
@@ -2381,6 +2434,10 @@ fn default_non_cli_excluded_tools() -> Vec<String> {
         "delegate",
         "screenshot",
         "image_info",
+        "media_clip",
+        "media_mux",
+        "media_extract_audio",
+        "media_chapter_split",
     ]
     .into_iter()
     .map(std::string::ToString::to_string)
@@ -5684,6 +5741,9 @@ impl Config {
         if self.gateway.host.trim().is_empty() {
             anyhow::bail!("gateway.host must not be empty");
         }
+        if self.gateway.tool_execution_max_concurrent == 0 {
+            anyhow::bail!("gateway.tool_execution_max_concurrent must be greater than 0");
+        }
 
         // Autonomy
         if self.autonomy.max_actions_per_hour == 0 {
@@ -5717,6 +5777,13 @@ impl Config {
             }
         }
 
+        // Agent
+        for (tool_name, defaults) in &self.agent.tool_defaults {
+            if !defaults.is_object() {
+                anyhow::bail!("agent.tool_defaults.{tool_name} must be a table of arguments");
+            }
+        }
+
         // Security OTP / estop
         if self.security.otp.token_ttl_secs == 0 {
             anyhow::bail!("security.otp.token_ttl_secs must be greater than 0");
@@ -5935,6 +6002,17 @@ impl Config {
         if self.coordination.max_seen_message_ids == 0 {
             anyhow::bail!("coordination.max_seen_message_ids must be greater than 0");
         }
+        if self.transcription.connect_timeout_secs == 0 {
+            anyhow::bail!("transcription.connect_timeout_secs must be greater than 0");
+        }
+        if self.transcription.request_timeout_secs == 0 {
+            anyhow::bail!("transcription.request_timeout_secs must be greater than 0");
+        }
+        if self.transcription.max_request_timeout_secs < self.transcription.request_timeout_secs {
+            anyhow::bail!(
+                "transcription.max_request_timeout_secs must be at least transcription.request_timeout_secs"
+            );
+        }
 
         Ok(())
     }
@@ -7180,6 +7258,7 @@ reasoning_level = "high"
         assert_eq!(cfg.max_history_messages, 50);
         assert!(!cfg.parallel_tools);
         assert_eq!(cfg.tool_dispatcher, "auto");
+        assert!(cfg.tool_defaults.is_empty());
     }
 
     #[test]
@@ -7201,6 +7280,32 @@ tool_dispatcher = "xml"
         assert_eq!(parsed.agent.tool_dispatcher, "xml");
     }
 
+    #[test]
+    async fn agent_tool_defaults_deserialize_as_argument_tables() {
+        let raw = r#"
+default_temperature = 0.7
+[agent.tool_defaults.media_clip]
+reencode = true
+"#;
+        let parsed: Config = toml::from_str(raw).unwrap();
+        assert_eq!(
+            parsed.agent.tool_defaults["media_clip"],
+            serde_json::json!({"reencode": true})
+        );
+        parsed.validate().unwrap();
+    }
+
+    #[test]
+    async fn validate_rejects_non_table_tool_defaults() {
+        let mut config = Config::default();
+        config
+            .agent
+            .tool_defaults
+            .insert("shell".into(), serde_json::json!("not-a-table"));
+        let err = config.validate().unwrap_err();
+        assert!(err.to_string().contains("agent.tool_defaults.shell"));
+    }
+
     #[tokio::test]
     async fn sync_directory_handles_existing_directory() {
         let dir = std::env::temp_dir().join(format!(
@@ -8141,6 +8246,8 @@ channel_id = "C123"
                 auth_token: Some("node-token".into()),
                 allowed_node_ids: vec!["node-1".into(), "node-2".into()],
             },
+            tool_execution_enabled: true,
+            tool_execution_max_concurrent: 2,
         };
         let toml_str = toml::to_string(&g).unwrap();
         let parsed: GatewayConfig = toml::from_str(&toml_str).unwrap();
@@ -8162,6 +8269,8 @@ channel_id = "C123"
             parsed.node_control.allowed_node_ids,
             vec!["node-1", "node-2"]
         );
+        assert!(parsed.tool_execution_enabled);
+        assert_eq!(parsed.tool_execution_max_concurrent, 2);
     }
 
     #[test]
@@ -9873,6 +9982,31 @@ default_model = "legacy-model"
         assert_eq!(tc.model, "whisper-large-v3-turbo");
         assert!(tc.language.is_none());
         assert_eq!(tc.max_duration_secs, 120);
+        assert_eq!(tc.connect_timeout_secs, 10);
+        assert_eq!(tc.request_timeout_secs, 30);
+        assert_eq!(tc.request_timeout_secs_per_mib, 12);
+        assert_eq!(tc.max_request_timeout_secs, 300);
+    }
+
+    #[test]
+    async fn validate_rejects_zero_tool_execution_concurrency() {
+        let mut config = Config::default();
+        config.gateway.tool_execution_max_concurrent = 0;
+        let err = config.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("gateway.tool_execution_max_concurrent"));
+    }
+
+    #[test]
+    async fn validate_rejects_inverted_transcription_timeouts() {
+        let mut config = Config::default();
+        config.transcription.request_timeout_secs = 60;
+        config.transcription.max_request_timeout_secs = 30;
+        let err = config.validate().unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("transcription.max_request_timeout_secs"));
     }
 
     #[test]
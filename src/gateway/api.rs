@@ -12,6 +12,11 @@ use serde::Deserialize;
 
 const MASKED_SECRET: &str = "***MASKED***";
 
+/// Request timeout for `POST /api/tools/{name}`, in place of the gateway-wide
+/// 30s: long enough for media tools to hit their own 600s ffmpeg limit and
+/// clean up before the request is dropped.
+pub(super) const TOOL_EXECUTION_TIMEOUT_SECS: u64 = 900;
+
 // ── Bearer token auth extractor ─────────────────────────────────
 
 /// Extract and validate bearer token from Authorization header.
@@ -216,6 +221,191 @@ pub async fn handle_api_tools(
     Json(serde_json::json!({"tools": tools})).into_response()
 }
 
+/// Why a direct tool execution request is refused, if it is.
+///
+/// There is no one to answer an approval prompt on this surface, so tools
+/// that would need approval are refused, like on other non-CLI channels.
+fn tool_execution_denial(
+    config: &crate::config::Config,
+    name: &str,
+) -> Option<(StatusCode, &'static str)> {
+    if !config.gateway.tool_execution_enabled {
+        return Some((StatusCode::NOT_FOUND, "Tool execution API is disabled"));
+    }
+    if config
+        .autonomy
+        .non_cli_excluded_tools
+        .iter()
+        .any(|excluded| excluded.trim() == name)
+    {
+        return Some((
+            StatusCode::FORBIDDEN,
+            "Tool is excluded from non-CLI surfaces (autonomy.non_cli_excluded_tools)",
+        ));
+    }
+    if crate::approval::ApprovalManager::from_config(&config.autonomy).needs_approval(name) {
+        return Some((
+            StatusCode::FORBIDDEN,
+            "Tool requires approval; add it to autonomy.auto_approve to run it via the API",
+        ));
+    }
+    None
+}
+
+fn tool_execution_error(
+    status: StatusCode,
+    message: impl Into<String>,
+) -> axum::response::Response {
+    (status, Json(serde_json::json!({"error": message.into()}))).into_response()
+}
+
+/// POST /api/tools/{name} — execute a registered tool with the JSON body as arguments
+pub async fn handle_api_tool_execute(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(name): Path<String>,
+    body: Result<Json<serde_json::Value>, axum::extract::rejection::JsonRejection>,
+) -> impl IntoResponse {
+    use crate::agent::loop_::scrub_credentials;
+    use crate::observability::runtime_trace;
+
+    if let Err(e) = require_auth(&state, &headers) {
+        return e.into_response();
+    }
+
+    let denial = {
+        let config = state.config.lock();
+        tool_execution_denial(&config, &name)
+    };
+    if let Some((status, message)) = denial {
+        return tool_execution_error(status, message);
+    }
+
+    let Json(mut args) = match body {
+        Ok(body) => body,
+        Err(e) => {
+            return tool_execution_error(StatusCode::BAD_REQUEST, format!("Invalid JSON body: {e}"))
+        }
+    };
+
+    let Ok(_slot) = state.tool_execution_slots.clone().try_acquire_owned() else {
+        return tool_execution_error(
+            StatusCode::TOO_MANY_REQUESTS,
+            "Too many concurrent tool executions; retry later",
+        );
+    };
+
+    // ── Hook: before_tool_call (modifying) ──────────
+    let mut tool_name = name;
+    if let Some(hooks) = &state.hooks {
+        match hooks
+            .run_before_tool_call(tool_name.clone(), args.clone())
+            .await
+        {
+            crate::hooks::HookResult::Cancel(reason) => {
+                let cancelled = format!("Cancelled by hook: {reason}");
+                runtime_trace::record_event(
+                    "tool_call_result",
+                    Some("gateway"),
+                    None,
+                    None,
+                    None,
+                    Some(false),
+                    Some(&cancelled),
+                    serde_json::json!({
+                        "tool": tool_name,
+                        "arguments": scrub_credentials(&args.to_string()),
+                    }),
+                );
+                return tool_execution_error(StatusCode::FORBIDDEN, scrub_credentials(&cancelled));
+            }
+            crate::hooks::HookResult::Continue((hooked_name, hooked_args)) => {
+                // A hook may rename the call; the new target must pass the same gates.
+                if hooked_name != tool_name {
+                    let denial = {
+                        let config = state.config.lock();
+                        tool_execution_denial(&config, &hooked_name)
+                    };
+                    if let Some((status, message)) = denial {
+                        return tool_execution_error(status, message);
+                    }
+                }
+                tool_name = hooked_name;
+                args = hooked_args;
+            }
+        }
+    }
+
+    let Some(tool) = state
+        .tools_registry_exec
+        .iter()
+        .find(|tool| tool.name() == tool_name)
+    else {
+        return tool_execution_error(StatusCode::NOT_FOUND, format!("Unknown tool: {tool_name}"));
+    };
+
+    runtime_trace::record_event(
+        "tool_call_start",
+        Some("gateway"),
+        None,
+        None,
+        None,
+        None,
+        None,
+        serde_json::json!({
+            "tool": tool_name,
+            "arguments": scrub_credentials(&args.to_string()),
+        }),
+    );
+
+    let started = std::time::Instant::now();
+    let result = match tool.execute(args).await {
+        Ok(result) => result,
+        Err(e) => {
+            let message = scrub_credentials(&format!("Tool execution failed: {e}"));
+            runtime_trace::record_event(
+                "tool_call_result",
+                Some("gateway"),
+                None,
+                None,
+                None,
+                Some(false),
+                Some(&message),
+                serde_json::json!({
+                    "tool": tool_name,
+                    "duration_ms": started.elapsed().as_millis(),
+                }),
+            );
+            return tool_execution_error(StatusCode::INTERNAL_SERVER_ERROR, message);
+        }
+    };
+    let duration = started.elapsed();
+
+    runtime_trace::record_event(
+        "tool_call_result",
+        Some("gateway"),
+        None,
+        None,
+        None,
+        Some(result.success),
+        result.error.as_deref().map(scrub_credentials).as_deref(),
+        serde_json::json!({
+            "tool": tool_name,
+            "duration_ms": duration.as_millis(),
+            "output": scrub_credentials(&result.output),
+        }),
+    );
+
+    // ── Hook: after_tool_call (void) ─────────────────
+    if let Some(hooks) = &state.hooks {
+        hooks
+            .fire_after_tool_call(&tool_name, &result, duration)
+            .await;
+    }
+
+    Json(result).into_response()
+}
+
 /// GET /api/cron — list cron jobs
 pub async fn handle_api_cron_list(
     State(state): State<AppState>,
@@ -893,6 +1083,50 @@ mod tests {
         CloudflareTunnelConfig, LarkReceiveMode, NgrokTunnelConfig, WatiConfig,
     };
 
+    #[test]
+    fn tool_execution_is_disabled_by_default() {
+        let cfg = crate::config::Config::default();
+        let denial = tool_execution_denial(&cfg, "file_read");
+        assert_eq!(
+            denial.map(|(status, _)| status),
+            Some(StatusCode::NOT_FOUND)
+        );
+    }
+
+    #[test]
+    fn tool_execution_refuses_non_cli_excluded_tools() {
+        let mut cfg = crate::config::Config::default();
+        cfg.gateway.tool_execution_enabled = true;
+
+        let denial = tool_execution_denial(&cfg, "shell");
+        assert_eq!(
+            denial.map(|(status, _)| status),
+            Some(StatusCode::FORBIDDEN)
+        );
+        assert!(tool_execution_denial(&cfg, "file_read").is_none());
+    }
+
+    #[test]
+    fn tool_execution_refuses_tools_that_need_approval() {
+        let mut cfg = crate::config::Config::default();
+        cfg.gateway.tool_execution_enabled = true;
+
+        // Not non-CLI excluded, but not auto-approved either.
+        assert!(!cfg
+            .autonomy
+            .non_cli_excluded_tools
+            .iter()
+            .any(|tool| tool == "apply_patch"));
+        let denial = tool_execution_denial(&cfg, "apply_patch");
+        assert_eq!(
+            denial.map(|(status, _)| status),
+            Some(StatusCode::FORBIDDEN)
+        );
+
+        cfg.autonomy.auto_approve.push("apply_patch".into());
+        assert!(tool_execution_denial(&cfg, "apply_patch").is_none());
+    }
+
     #[test]
     fn masking_keeps_toml_valid_and_preserves_api_keys_type() {
         let mut cfg = crate::config::Config::default();
@@ -324,6 +324,10 @@ pub struct AppState {
     pub cost_tracker: Option<Arc<CostTracker>>,
     /// SSE broadcast channel for real-time events
     pub event_tx: tokio::sync::broadcast::Sender<serde_json::Value>,
+    /// Lifecycle hooks (before/after tool call) for direct tool execution
+    pub hooks: Option<Arc<crate::hooks::HookRunner>>,
+    /// Caps concurrent `POST /api/tools/{name}` executions
+    pub tool_execution_slots: Arc<tokio::sync::Semaphore>,
 }
 
 /// Run the HTTP gateway using axum with proper HTTP/1.1 compliance.
@@ -692,6 +696,10 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
         max_tool_iterations,
         cost_tracker,
         event_tx,
+        hooks,
+        tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(
+            config.gateway.tool_execution_max_concurrent,
+        )),
     };
 
     // Config PUT needs larger body limit (1MB)
@@ -711,6 +719,17 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
             openai_compat::CHAT_COMPLETIONS_MAX_BODY_SIZE,
         ));
 
+    // Direct tool execution can run ffmpeg for minutes, so it gets its own
+    // timeout and is merged after the gateway-wide 30s timeout layer.
+    let tool_execute_router = Router::new()
+        .route("/api/tools/{name}", post(api::handle_api_tool_execute))
+        .with_state(state.clone())
+        .layer(RequestBodyLimitLayer::new(MAX_BODY_SIZE))
+        .layer(TimeoutLayer::with_status_code(
+            StatusCode::REQUEST_TIMEOUT,
+            Duration::from_secs(api::TOOL_EXECUTION_TIMEOUT_SECS),
+        ));
+
     // Build router with middleware
     let app = Router::new()
         // ── Existing routes ──
@@ -761,6 +780,7 @@ pub async fn run_gateway(host: &str, port: u16, config: Config) -> Result<()> {
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(REQUEST_TIMEOUT_SECS),
         ))
+        .merge(tool_execute_router)
         // ── SPA fallback: non-API GET requests serve index.html ──
         .fallback(get(static_files::handle_spa_fallback));
 
@@ -2033,6 +2053,8 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_metrics(State(state), test_connect_info(), HeaderMap::new())
@@ -2089,6 +2111,8 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_metrics(State(state), test_connect_info(), HeaderMap::new())
@@ -2131,6 +2155,8 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_metrics(State(state), test_public_connect_info(), HeaderMap::new())
@@ -2174,6 +2200,8 @@ mod tests {
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let unauthorized =
@@ -2193,6 +2221,183 @@ mod tests {
         assert_eq!(authorized.status(), StatusCode::OK);
     }
 
+    /// Echoes its arguments; named `file_read` so it is auto-approved by default.
+    struct EchoArgsTool;
+
+    #[async_trait]
+    impl Tool for EchoArgsTool {
+        fn name(&self) -> &str {
+            "file_read"
+        }
+
+        fn description(&self) -> &str {
+            "Echoes its arguments"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({ "type": "object" })
+        }
+
+        async fn execute(
+            &self,
+            args: serde_json::Value,
+        ) -> anyhow::Result<crate::tools::ToolResult> {
+            Ok(crate::tools::ToolResult {
+                success: true,
+                output: args.to_string(),
+                error: None,
+            })
+        }
+    }
+
+    struct CancelToolCallHook;
+
+    #[async_trait]
+    impl crate::hooks::HookHandler for CancelToolCallHook {
+        fn name(&self) -> &str {
+            "cancel-tool-call"
+        }
+
+        async fn before_tool_call(
+            &self,
+            _name: String,
+            _args: serde_json::Value,
+        ) -> crate::hooks::HookResult<(String, serde_json::Value)> {
+            crate::hooks::HookResult::Cancel("blocked by policy".into())
+        }
+    }
+
+    fn tool_execution_state(tool_execution_enabled: bool, pairing: PairingGuard) -> AppState {
+        let mut config = Config::default();
+        config.gateway.tool_execution_enabled = tool_execution_enabled;
+        AppState {
+            config: Arc::new(Mutex::new(config)),
+            provider: Arc::new(MockProvider::default()),
+            model: "test-model".into(),
+            temperature: 0.0,
+            mem: Arc::new(MockMemory),
+            auto_save: false,
+            webhook_secret_hash: None,
+            pairing: Arc::new(pairing),
+            trust_forwarded_headers: false,
+            rate_limiter: Arc::new(GatewayRateLimiter::new(100, 100, 100)),
+            idempotency_store: Arc::new(IdempotencyStore::new(Duration::from_secs(300), 1000)),
+            whatsapp: None,
+            whatsapp_app_secret: None,
+            linq: None,
+            linq_signing_secret: None,
+            nextcloud_talk: None,
+            nextcloud_talk_webhook_secret: None,
+            wati: None,
+            qq: None,
+            qq_webhook_enabled: false,
+            observer: Arc::new(crate::observability::NoopObserver),
+            tools_registry: Arc::new(Vec::new()),
+            tools_registry_exec: Arc::new(vec![Box::new(EchoArgsTool) as Box<dyn Tool>]),
+            multimodal: crate::config::MultimodalConfig::default(),
+            max_tool_iterations: 10,
+            cost_tracker: None,
+            event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
+        }
+    }
+
+    async fn execute_tool(
+        state: AppState,
+        headers: HeaderMap,
+        name: &str,
+    ) -> (StatusCode, serde_json::Value) {
+        let response = api::handle_api_tool_execute(
+            State(state),
+            headers,
+            axum::extract::Path(name.to_string()),
+            Ok(Json(serde_json::json!({ "path": "notes.txt" }))),
+        )
+        .await
+        .into_response();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn tool_execute_requires_bearer_token_when_pairing_is_enabled() {
+        let paired_token = "zc_test_token".to_string();
+        let state = tool_execution_state(
+            true,
+            PairingGuard::new(true, std::slice::from_ref(&paired_token)),
+        );
+
+        let (status, _) = execute_tool(state.clone(), HeaderMap::new(), "file_read").await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {paired_token}")).unwrap(),
+        );
+        let (status, _) = execute_tool(state, headers, "file_read").await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn tool_execute_is_not_found_when_disabled_or_unknown() {
+        let state = tool_execution_state(false, PairingGuard::new(false, &[]));
+        let (status, body) = execute_tool(state, HeaderMap::new(), "file_read").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body["error"].as_str().unwrap().contains("disabled"));
+
+        // memory_recall is auto-approved by default but not registered here.
+        let state = tool_execution_state(true, PairingGuard::new(false, &[]));
+        let (status, body) = execute_tool(state, HeaderMap::new(), "memory_recall").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body["error"].as_str().unwrap().contains("Unknown tool"));
+    }
+
+    #[tokio::test]
+    async fn tool_execute_rejects_when_all_slots_are_busy() {
+        let state = tool_execution_state(true, PairingGuard::new(false, &[]));
+        let _held = state
+            .tool_execution_slots
+            .clone()
+            .try_acquire_owned()
+            .unwrap();
+
+        let (status, _) = execute_tool(state, HeaderMap::new(), "file_read").await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn tool_execute_honours_hook_cancellation() {
+        let mut hooks = crate::hooks::HookRunner::new();
+        hooks.register(Box::new(CancelToolCallHook));
+        let mut state = tool_execution_state(true, PairingGuard::new(false, &[]));
+        state.hooks = Some(Arc::new(hooks));
+
+        let (status, body) = execute_tool(state, HeaderMap::new(), "file_read").await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(body["error"]
+            .as_str()
+            .unwrap()
+            .contains("Cancelled by hook: blocked by policy"));
+    }
+
+    #[tokio::test]
+    async fn tool_execute_returns_serialized_tool_result() {
+        let state = tool_execution_state(true, PairingGuard::new(false, &[]));
+        let (status, body) = execute_tool(state, HeaderMap::new(), "file_read").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "success": true,
+                "output": r#"{"path":"notes.txt"}"#,
+                "error": null
+            })
+        );
+    }
+
     #[test]
     fn gateway_rate_limiter_blocks_after_limit() {
         let limiter = GatewayRateLimiter::new(2, 2, 100);
@@ -2643,6 +2848,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let mut headers = HeaderMap::new();
@@ -2710,6 +2917,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_webhook(
@@ -2759,6 +2968,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_node_control(
@@ -2813,6 +3024,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_node_control(
@@ -2872,6 +3085,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let headers = HeaderMap::new();
@@ -2953,6 +3168,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_webhook(
@@ -3006,6 +3223,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let mut headers = HeaderMap::new();
@@ -3064,6 +3283,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let mut headers = HeaderMap::new();
@@ -3127,6 +3348,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_nextcloud_talk_webhook(
@@ -3186,6 +3409,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let mut headers = HeaderMap::new();
@@ -3238,6 +3463,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let response = handle_qq_webhook(
@@ -3289,6 +3516,8 @@ Reminder set successfully."#;
             max_tool_iterations: 10,
             cost_tracker: None,
             event_tx: tokio::sync::broadcast::channel(16).0,
+            hooks: None,
+            tool_execution_slots: Arc::new(tokio::sync::Semaphore::new(1)),
         };
 
         let mut headers = HeaderMap::new();
@@ -0,0 +1,176 @@
+//! Resolution of external binaries that media tools shell out to.
+//!
+//! Each binary can be pinned with a `ZEROCLAW_<NAME>_PATH` environment
+//! variable for non-standard installs (pipx, Homebrew on Apple Silicon, ...);
+//! otherwise the directories on `PATH` are searched.
+
+use std::path::PathBuf;
+
+/// External binaries used by tools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalBinary {
+    Ffmpeg,
+    Ffprobe,
+}
+
+impl ExternalBinary {
+    /// Executable name looked up on `PATH`.
+    pub fn program_name(self) -> &'static str {
+        match self {
+            Self::Ffmpeg => "ffmpeg",
+            Self::Ffprobe => "ffprobe",
+        }
+    }
+
+    /// Environment variable that overrides the `PATH` lookup.
+    pub fn env_var(self) -> &'static str {
+        match self {
+            Self::Ffmpeg => "ZEROCLAW_FFMPEG_PATH",
+            Self::Ffprobe => "ZEROCLAW_FFPROBE_PATH",
+        }
+    }
+}
+
+/// Resolves [`ExternalBinary`] locations from the environment.
+pub struct BinaryResolver;
+
+impl BinaryResolver {
+    /// Resolve a binary using the process environment.
+    pub fn resolve(binary: ExternalBinary) -> Result<PathBuf, String> {
+        Self::resolve_with(
+            binary,
+            std::env::var_os(binary.env_var()).map(PathBuf::from),
+            std::env::var_os("PATH"),
+        )
+    }
+
+    /// Resolve a binary from an explicit override and `PATH` value.
+    ///
+    /// `PATH` lookup goes through [`which::which_in`], so only executable
+    /// files match and `PATHEXT` is honoured on Windows. The error message
+    /// lists every location that was checked.
+    fn resolve_with(
+        binary: ExternalBinary,
+        override_path: Option<PathBuf>,
+        path_var: Option<std::ffi::OsString>,
+    ) -> Result<PathBuf, String> {
+        if let Some(path) = override_path.filter(|p| !p.as_os_str().is_empty()) {
+            if path.is_file() {
+                return Ok(path);
+            }
+            // An explicit override that points nowhere is a misconfiguration;
+            // do not silently fall back to a different binary on PATH.
+            return Err(format!(
+                "{} not found at {} (set by {})",
+                binary.program_name(),
+                path.display(),
+                binary.env_var()
+            ));
+        }
+
+        let cwd = std::env::current_dir().unwrap_or_default();
+        if let Ok(found) = which::which_in(binary.program_name(), path_var.as_ref(), cwd) {
+            return Ok(found);
+        }
+
+        let searched: Vec<String> = path_var
+            .as_ref()
+            .map(|p| {
+                std::env::split_paths(p)
+                    .map(|dir| dir.display().to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Err(format!(
+            "{} not found. Set {} or install it on PATH. Searched: {}",
+            binary.program_name(),
+            binary.env_var(),
+            if searched.is_empty() {
+                "(PATH is empty)".to_string()
+            } else {
+                searched.join(", ")
+            }
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_path_wins_when_file_exists() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bin = tmp.path().join("my-ffmpeg");
+        std::fs::write(&bin, b"").unwrap();
+
+        let resolved =
+            BinaryResolver::resolve_with(ExternalBinary::Ffmpeg, Some(bin.clone()), None).unwrap();
+        assert_eq!(resolved, bin);
+    }
+
+    #[test]
+    fn missing_override_path_is_an_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = BinaryResolver::resolve_with(
+            ExternalBinary::Ffmpeg,
+            Some(tmp.path().join("nope")),
+            None,
+        )
+        .unwrap_err();
+        assert!(err.contains("ZEROCLAW_FFMPEG_PATH"));
+    }
+
+    /// Create an empty executable named `name` in `dir`.
+    fn write_executable(dir: &std::path::Path, name: &str) -> PathBuf {
+        let file_name = if cfg!(target_os = "windows") {
+            format!("{name}.exe")
+        } else {
+            name.to_string()
+        };
+        let path = dir.join(file_name);
+        std::fs::write(&path, b"").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn falls_back_to_path_lookup() {
+        let tmp = tempfile::tempdir().unwrap();
+        let bin = write_executable(tmp.path(), "ffmpeg");
+        let path_var = std::env::join_paths([tmp.path()]).unwrap();
+
+        let resolved =
+            BinaryResolver::resolve_with(ExternalBinary::Ffmpeg, None, Some(path_var)).unwrap();
+        assert_eq!(resolved, bin);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_lookup_skips_non_executable_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("ffmpeg"), b"").unwrap();
+        let path_var = std::env::join_paths([tmp.path()]).unwrap();
+
+        assert!(
+            BinaryResolver::resolve_with(ExternalBinary::Ffmpeg, None, Some(path_var)).is_err()
+        );
+    }
+
+    #[test]
+    fn error_lists_searched_locations() {
+        let a = tempfile::tempdir().unwrap();
+        let b = tempfile::tempdir().unwrap();
+        let path_var = std::env::join_paths([a.path(), b.path()]).unwrap();
+
+        let err =
+            BinaryResolver::resolve_with(ExternalBinary::Ffmpeg, None, Some(path_var)).unwrap_err();
+        assert!(err.contains(&a.path().display().to_string()));
+        assert!(err.contains(&b.path().display().to_string()));
+        assert!(err.contains("ZEROCLAW_FFMPEG_PATH"));
+    }
+}
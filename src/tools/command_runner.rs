@@ -0,0 +1,410 @@
+//! Shared subprocess runner for tools that shell out to external binaries.
+//!
+//! Spawns the command with `kill_on_drop`, captures stdout/stderr, and maps
+//! spawn failures, timeouts and cancellation to a single [`CommandError`] type
+//! so callers report them consistently. Only the tail of stderr is handed back
+//! to the model, capped at [`DEFAULT_STDERR_TAIL_BYTES`] unless a caller asks
+//! for a different limit.
+//!
+//! There is no concurrency limit here yet: it belongs on a process-wide
+//! limiter shared with the shell tool, which does not exist, and a
+//! runner-local semaphore would only cap the media tools.
+
+use crate::security::{AuditEvent, AuditEventType, AuditLogger};
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::ExitStatus;
+use std::time::{Duration, Instant};
+use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
+
+/// Default cap on subprocess stderr returned to the model (8 KB).
+pub const DEFAULT_STDERR_TAIL_BYTES: usize = 8 * 1024;
+
+/// Captured result of a command that ran to completion.
+#[derive(Debug)]
+pub struct CommandOutcome {
+    pub stdout: String,
+    pub stderr: String,
+    pub status: ExitStatus,
+}
+
+impl CommandOutcome {
+    pub fn success(&self) -> bool {
+        self.status.success()
+    }
+
+    /// Trimmed stderr, keeping only its last `max_bytes`.
+    pub fn stderr_tail(&self, max_bytes: usize) -> String {
+        crate::util::tail_with_truncation_marker(self.stderr.trim(), max_bytes)
+    }
+
+    /// Why the command failed: the stderr tail, or the exit status when
+    /// stderr is empty.
+    pub fn failure_message(&self, program: &str, max_bytes: usize) -> String {
+        let stderr = self.stderr_tail(max_bytes);
+        if stderr.is_empty() {
+            format!("{program} exited with status {}", self.status)
+        } else {
+            format!("{program} failed: {stderr}")
+        }
+    }
+}
+
+/// Why a command did not produce a [`CommandOutcome`].
+#[derive(Debug, thiserror::Error)]
+pub enum CommandError {
+    #[error("{program} not found at {path}")]
+    NotFound { program: String, path: String },
+
+    #[error("{program} timed out after {timeout:?}")]
+    TimedOut { program: String, timeout: Duration },
+
+    #[error("{program} was cancelled")]
+    Cancelled { program: String },
+
+    #[error("Failed to execute {program}: {source}")]
+    Io {
+        program: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Run `cmd` to completion, optionally bounded by `timeout` and `cancel`.
+///
+/// The child is killed if the timeout elapses or the token is cancelled. A
+/// nonzero exit status is not an error; check [`CommandOutcome::success`].
+pub async fn run_command(
+    mut cmd: Command,
+    timeout: Option<Duration>,
+    cancel: Option<&CancellationToken>,
+) -> Result<CommandOutcome, CommandError> {
+    let path = cmd.as_std().get_program().to_string_lossy().into_owned();
+    let program = program_name(&path);
+
+    let output = cmd.kill_on_drop(true).output();
+    let bounded = async {
+        match timeout {
+            Some(limit) => tokio::time::timeout(limit, output).await.map_err(|_| limit),
+            None => Ok(output.await),
+        }
+    };
+    // Dropping the losing future kills the child via `kill_on_drop`.
+    let bounded = if let Some(token) = cancel {
+        tokio::select! {
+            () = token.cancelled() => return Err(CommandError::Cancelled { program }),
+            result = bounded => result,
+        }
+    } else {
+        bounded.await
+    };
+    let result = match bounded {
+        Ok(result) => result,
+        Err(limit) => {
+            return Err(CommandError::TimedOut {
+                program,
+                timeout: limit,
+            })
+        }
+    };
+
+    match result {
+        Ok(out) => Ok(CommandOutcome {
+            stdout: String::from_utf8_lossy(&out.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&out.stderr).into_owned(),
+            status: out.status,
+        }),
+        Err(e) if e.kind() == ErrorKind::NotFound => Err(CommandError::NotFound { program, path }),
+        Err(source) => Err(CommandError::Io { program, source }),
+    }
+}
+
+/// Run a command that writes `output`, such as an ffmpeg encode.
+///
+/// On a nonzero exit, timeout or cancellation the partial `output` is removed and the
+/// returned error is ready to show to the model, with stderr capped at
+/// `stderr_tail_bytes`. The partial output is also removed if this future is
+/// dropped mid-run, e.g. when an HTTP request times out.
+///
+/// Every run that started is recorded in `audit`, with the full stderr of a
+/// failed run as the result error.
+pub async fn run_output_command(
+    cmd: Command,
+    timeout: Option<Duration>,
+    cancel: Option<&CancellationToken>,
+    output: &Path,
+    stderr_tail_bytes: usize,
+    audit: Option<&AuditLogger>,
+) -> Result<CommandOutcome, String> {
+    let program = program_name(&cmd.as_std().get_program().to_string_lossy());
+    let audit = audit.map(|logger| (logger, command_line(&cmd)));
+    let mut cleanup = RemoveOnDrop {
+        path: output,
+        armed: true,
+    };
+    let started = Instant::now();
+    let result = run_command(cmd, timeout, cancel).await;
+    let audit_result = match &result {
+        Ok(out) if out.success() => Some((true, out.status.code(), None)),
+        Ok(out) => Some((false, out.status.code(), Some(out.stderr.clone()))),
+        Err(CommandError::NotFound { .. } | CommandError::Io { .. }) => None,
+        Err(error) => Some((false, None, Some(error.to_string()))),
+    };
+    if let (Some((logger, command)), Some((success, exit_code, error))) = (audit, audit_result) {
+        let duration_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+        let event = AuditEvent::new(AuditEventType::CommandExecution)
+            .with_actor("tool".to_string(), None, None)
+            .with_action(command, "low".to_string(), true, true)
+            .with_result(success, exit_code, duration_ms, error);
+        if let Err(e) = logger.log(&event) {
+            tracing::warn!("failed to write {program} audit event: {e}");
+        }
+    }
+
+    match result {
+        Ok(out) if out.success() => {
+            cleanup.armed = false;
+            Ok(out)
+        }
+        Ok(out) => {
+            tracing::debug!(stderr = %out.stderr, "{program} failed");
+            Err(out.failure_message(&program, stderr_tail_bytes))
+        }
+        Err(error) => {
+            // The command never ran, so there is no partial output to remove.
+            if matches!(
+                error,
+                CommandError::NotFound { .. } | CommandError::Io { .. }
+            ) {
+                cleanup.armed = false;
+            }
+            Err(error.to_string())
+        }
+    }
+}
+
+/// Program and arguments of `cmd` as a single line, for the audit log.
+fn command_line(cmd: &Command) -> String {
+    let cmd = cmd.as_std();
+    std::iter::once(cmd.get_program())
+        .chain(cmd.get_args())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Removes `path` when dropped while still armed.
+struct RemoveOnDrop<'a> {
+    path: &'a Path,
+    armed: bool,
+}
+
+impl Drop for RemoveOnDrop<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            let _ = std::fs::remove_file(self.path);
+        }
+    }
+}
+
+/// File name of `path`, used to name the program in messages.
+fn program_name(path: &str) -> String {
+    Path::new(path).file_name().map_or_else(
+        || path.to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    fn sh(script: &str) -> Command {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", script]);
+        cmd
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn captures_stdout_and_stderr_on_success() {
+        let outcome = run_command(sh("echo out; echo err >&2"), None, None)
+            .await
+            .unwrap();
+        assert!(outcome.success());
+        assert_eq!(outcome.stdout.trim(), "out");
+        assert_eq!(outcome.stderr.trim(), "err");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn nonzero_exit_is_reported_in_outcome() {
+        let outcome = run_command(
+            sh("echo boom >&2; exit 3"),
+            Some(Duration::from_secs(10)),
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(!outcome.success());
+        assert_eq!(outcome.status.code(), Some(3));
+        assert_eq!(outcome.stderr.trim(), "boom");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn timeout_kills_and_reports_program() {
+        let err = run_command(sh("sleep 5"), Some(Duration::from_millis(100)), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CommandError::TimedOut { .. }));
+        assert_eq!(err.to_string(), "sh timed out after 100ms");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn cancellation_kills_and_reports_program() {
+        let token = CancellationToken::new();
+        let cancel = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            cancel.cancel();
+        });
+
+        let started = std::time::Instant::now();
+        let err = run_command(sh("sleep 5"), Some(Duration::from_secs(10)), Some(&token))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CommandError::Cancelled { .. }));
+        assert_eq!(err.to_string(), "sh was cancelled");
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stderr_tail_keeps_the_end_of_long_output() {
+        let outcome = run_command(
+            sh("printf 'a%.0s' $(seq 1 100) >&2; echo tail >&2"),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+        let tail = outcome.stderr_tail(16);
+        assert!(tail.ends_with("tail"));
+        assert!(tail.len() < outcome.stderr.len());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn run_output_command_removes_partial_output_on_failure() {
+        let tmp = tempfile::tempdir().unwrap();
+        let output = tmp.path().join("out.mp4");
+        let script = format!(
+            "echo partial > '{}'; echo bad >&2; exit 1",
+            output.display()
+        );
+
+        let err = run_output_command(
+            sh(&script),
+            None,
+            None,
+            &output,
+            DEFAULT_STDERR_TAIL_BYTES,
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err, "sh failed: bad");
+        assert!(!output.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn run_output_command_removes_partial_output_when_dropped() {
+        let tmp = tempfile::tempdir().unwrap();
+        let output = tmp.path().join("out.mp4");
+        let script = format!("echo partial > '{}'; sleep 30", output.display());
+
+        let run = run_output_command(
+            sh(&script),
+            None,
+            None,
+            &output,
+            DEFAULT_STDERR_TAIL_BYTES,
+            None,
+        );
+        assert!(tokio::time::timeout(Duration::from_millis(500), run)
+            .await
+            .is_err());
+        assert!(!output.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn run_output_command_reports_status_without_stderr() {
+        let tmp = tempfile::tempdir().unwrap();
+        let err = run_output_command(
+            sh("exit 2"),
+            None,
+            None,
+            &tmp.path().join("out.mp4"),
+            DEFAULT_STDERR_TAIL_BYTES,
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert!(err.starts_with("sh exited with status"));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn run_output_command_audits_full_stderr_of_failed_runs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let audit = AuditLogger::new(
+            crate::config::AuditConfig {
+                log_path: "audit.log".into(),
+                ..crate::config::AuditConfig::default()
+            },
+            tmp.path().to_path_buf(),
+        )
+        .unwrap();
+        let stderr = format!("{}end", "x".repeat(64));
+
+        let err = run_output_command(
+            sh(&format!("echo {stderr} >&2; exit 1")),
+            None,
+            None,
+            &tmp.path().join("out.mp4"),
+            16,
+            Some(&audit),
+        )
+        .await
+        .unwrap_err();
+        assert!(err.len() < stderr.len());
+
+        let log = std::fs::read_to_string(tmp.path().join("audit.log")).unwrap();
+        let event: serde_json::Value = serde_json::from_str(log.trim()).unwrap();
+        assert_eq!(event["event_type"], "command_execution");
+        assert!(event["action"]["command"]
+            .as_str()
+            .unwrap()
+            .starts_with("sh -c echo"));
+        assert_eq!(event["result"]["success"], false);
+        assert_eq!(event["result"]["exit_code"], 1);
+        assert_eq!(event["result"]["error"].as_str().unwrap().trim(), stderr);
+    }
+
+    #[tokio::test]
+    async fn missing_program_is_not_found() {
+        let err = run_command(Command::new("/nonexistent/zeroclaw-ffmpeg"), None, None)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "zeroclaw-ffmpeg not found at /nonexistent/zeroclaw-ffmpeg"
+        );
+    }
+}
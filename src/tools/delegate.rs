@@ -953,6 +953,53 @@ mod tests {
         }
     }
 
+    /// Calls `echo_tool` without arguments, then answers with the tool output.
+    struct EmptyArgsToolThenEchoProvider;
+
+    #[async_trait]
+    impl Provider for EmptyArgsToolThenEchoProvider {
+        async fn chat_with_system(
+            &self,
+            _system_prompt: Option<&str>,
+            _message: &str,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<String> {
+            Ok("unused".to_string())
+        }
+
+        async fn chat(
+            &self,
+            request: ChatRequest<'_>,
+            _model: &str,
+            _temperature: f64,
+        ) -> anyhow::Result<ChatResponse> {
+            let tool_output = request
+                .messages
+                .iter()
+                .find(|m| m.role == "tool")
+                .map(|m| m.content.clone());
+            Ok(match tool_output {
+                Some(output) => ChatResponse {
+                    text: Some(output),
+                    tool_calls: Vec::new(),
+                    usage: None,
+                    reasoning_content: None,
+                },
+                None => ChatResponse {
+                    text: None,
+                    tool_calls: vec![ToolCall {
+                        id: "call_1".to_string(),
+                        name: "echo_tool".to_string(),
+                        arguments: "{}".to_string(),
+                    }],
+                    usage: None,
+                    reasoning_content: None,
+                },
+            })
+        }
+    }
+
     fn agentic_config(allowed_tools: Vec<String>, max_iterations: usize) -> DelegateAgentConfig {
         DelegateAgentConfig {
             provider: "openrouter".to_string(),
@@ -1313,6 +1360,26 @@ mod tests {
         assert!(result.output.contains("done"));
     }
 
+    #[tokio::test]
+    async fn execute_agentic_applies_tool_defaults_to_parent_tools() {
+        let config = agentic_config(vec!["echo_tool".to_string()], 10);
+        let defaults =
+            HashMap::from([("echo_tool".to_string(), json!({ "value": "from-defaults" }))]);
+        let parent_tools: Vec<Arc<dyn Tool>> = vec![Arc::new(EchoTool)];
+        let tool = DelegateTool::new(HashMap::new(), None, test_security()).with_parent_tools(
+            crate::tools::parent_tools_snapshot(&parent_tools, &defaults),
+        );
+
+        let provider = EmptyArgsToolThenEchoProvider;
+        let result = tool
+            .execute_agentic("agentic", &config, &provider, "run", 0.2)
+            .await
+            .unwrap();
+
+        assert!(result.success, "{:?}", result.error);
+        assert!(result.output.contains("echo:from-defaults"));
+    }
+
     #[tokio::test]
     async fn execute_agentic_excludes_delegate_even_if_allowlisted() {
         let config = agentic_config(vec!["delegate".to_string()], 10);
@@ -0,0 +1,527 @@
+use super::binary_resolver::{BinaryResolver, ExternalBinary};
+use super::command_runner::{run_command, run_output_command, DEFAULT_STDERR_TAIL_BYTES};
+use super::traits::{Tool, ToolResult};
+use super::workspace_file::resolve_workspace_file;
+use crate::security::{AuditLogger, SecurityPolicy};
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum time to wait for a single ffmpeg cut.
+const FFMPEG_TIMEOUT_SECS: u64 = 600;
+/// Maximum time to wait for the ffprobe chapter listing.
+const FFPROBE_TIMEOUT_SECS: u64 = 30;
+/// Refuse files with more chapters than this to bound the number of ffmpeg runs.
+const MAX_CHAPTERS: usize = 200;
+/// Maximum length (in characters) of the title part of a clip file name.
+const MAX_TITLE_CHARS: usize = 80;
+
+/// A chapter read from `ffprobe -show_chapters`.
+#[derive(Debug, Clone, PartialEq)]
+struct Chapter {
+    start: f64,
+    end: f64,
+    title: Option<String>,
+}
+
+/// Split a local media file into one stream-copied clip per chapter.
+///
+/// Chapters come from the file's own metadata (`ffprobe -show_chapters`).
+/// Clips are named `NN - <title>.<ext>` and written to `output_dir`, which
+/// defaults to a `<stem>_chapters` directory next to the input.
+pub struct MediaChapterSplitTool {
+    security: Arc<SecurityPolicy>,
+    audit: Option<Arc<AuditLogger>>,
+}
+
+impl MediaChapterSplitTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self {
+            security,
+            audit: None,
+        }
+    }
+
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Resolve (and create) the output directory, keeping it inside the workspace.
+    ///
+    /// Without `requested`, the directory is `<stem>_chapters` next to the
+    /// already-resolved `input`. That path is absolute, so it skips the
+    /// argument check (which rejects absolute paths under `workspace_only`)
+    /// and relies on the canonical-path check alone.
+    async fn resolve_output_dir(
+        &self,
+        requested: Option<&str>,
+        input: &Path,
+    ) -> Result<PathBuf, String> {
+        let candidate = match requested {
+            Some(dir_str) => {
+                if !self.security.is_path_allowed(dir_str) {
+                    return Err(format!(
+                        "Path not allowed: {dir_str} (must be within workspace)"
+                    ));
+                }
+                let dir = Path::new(dir_str);
+                if dir.is_absolute() {
+                    dir.to_path_buf()
+                } else {
+                    self.security.workspace_dir.join(dir)
+                }
+            }
+            None => {
+                let stem = input
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("media");
+                input.with_file_name(format!("{stem}_chapters"))
+            }
+        };
+
+        tokio::fs::create_dir_all(&candidate)
+            .await
+            .map_err(|e| format!("Failed to create output directory: {e}"))?;
+        let resolved = tokio::fs::canonicalize(&candidate)
+            .await
+            .map_err(|e| format!("Failed to resolve output directory: {e}"))?;
+
+        if !self.security.is_resolved_path_allowed(&resolved) {
+            return Err(self.security.resolved_path_violation_message(&resolved));
+        }
+
+        Ok(resolved)
+    }
+
+    /// Parse `ffprobe -show_chapters -print_format json` output.
+    fn parse_chapters(probe: &serde_json::Value) -> Result<Vec<Chapter>, String> {
+        let items = probe
+            .get("chapters")
+            .and_then(serde_json::Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let time = |key: &str| {
+                    item.get(key)
+                        .and_then(|v| v.as_str().map_or_else(|| v.as_f64(), |s| s.parse().ok()))
+                };
+                let title = item
+                    .pointer("/tags/title")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::trim)
+                    .filter(|t| !t.is_empty())
+                    .map(str::to_string);
+                match (time("start_time"), time("end_time")) {
+                    (Some(start), Some(end)) if end > start => Ok(Chapter { start, end, title }),
+                    _ => Err(format!("Chapter {i} has an invalid start/end time")),
+                }
+            })
+            .collect()
+    }
+
+    /// Make a chapter title safe to use as a file name component.
+    fn sanitize_title(title: &str) -> String {
+        let cleaned: String = title
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || matches!(c, ' ' | '-' | '_' | '.' | '(' | ')' | '\'') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .take(MAX_TITLE_CHARS)
+            .collect();
+        let cleaned = cleaned.trim().trim_matches('.').trim();
+        if cleaned.is_empty() {
+            "chapter".to_string()
+        } else {
+            cleaned.to_string()
+        }
+    }
+
+    /// Build `NN - <title>.<ext>` inside `dir`, padding the index to the chapter count.
+    fn chapter_output_path(
+        input: &Path,
+        dir: &Path,
+        index: usize,
+        total: usize,
+        title: Option<&str>,
+    ) -> PathBuf {
+        let width = total.to_string().len().max(2);
+        let title = title.map_or_else(|| format!("Chapter {}", index + 1), Self::sanitize_title);
+        let name = match input.extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{:0width$} - {title}.{ext}", index + 1),
+            None => format!("{:0width$} - {title}", index + 1),
+        };
+        dir.join(name)
+    }
+
+    fn ffmpeg_args(input: &Path, output: &Path, chapter: &Chapter) -> Vec<String> {
+        vec![
+            "-hide_banner".to_string(),
+            "-loglevel".into(),
+            "error".into(),
+            "-n".into(),
+            "-ss".into(),
+            format!("{:.3}", chapter.start),
+            "-i".into(),
+            input.to_string_lossy().into_owned(),
+            "-t".into(),
+            format!("{:.3}", chapter.end - chapter.start),
+            "-c".into(),
+            "copy".into(),
+            "-avoid_negative_ts".into(),
+            "make_zero".into(),
+            output.to_string_lossy().into_owned(),
+        ]
+    }
+
+    async fn probe_chapters(ffprobe: &Path, input: &Path) -> Result<Vec<Chapter>, String> {
+        let mut cmd = tokio::process::Command::new(ffprobe);
+        cmd.args(["-v", "error", "-show_chapters", "-print_format", "json"])
+            .arg(input);
+
+        let out = run_command(cmd, Some(Duration::from_secs(FFPROBE_TIMEOUT_SECS)), None)
+            .await
+            .map_err(|e| e.to_string())?;
+        if !out.success() {
+            return Err(format!(
+                "ffprobe could not read {}: {}",
+                input.display(),
+                out.stderr_tail(DEFAULT_STDERR_TAIL_BYTES)
+            ));
+        }
+        let probe: serde_json::Value = serde_json::from_str(&out.stdout)
+            .map_err(|e| format!("Invalid ffprobe output: {e}"))?;
+        Self::parse_chapters(&probe)
+    }
+}
+
+#[async_trait]
+impl Tool for MediaChapterSplitTool {
+    fn name(&self) -> &str {
+        "media_chapter_split"
+    }
+
+    fn description(&self) -> &str {
+        "Split a local audio/video file into one clip per chapter using its embedded chapter \
+         metadata (ffmpeg stream copy). Returns each clip path with its chapter title and times."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "input": {
+                    "type": "string",
+                    "description": "Path to the source media file (absolute or relative to workspace)"
+                },
+                "output_dir": {
+                    "type": "string",
+                    "description": "Directory for the chapter clips (default: '<stem>_chapters' next to the input). Must be within workspace."
+                }
+            },
+            "required": ["input"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let input_str = args
+            .get("input")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'input' parameter"))?;
+        let output_dir_str = args.get("output_dir").and_then(|v| v.as_str());
+
+        if !self.security.can_act() {
+            return Ok(ToolResult::failure("Action blocked: autonomy is read-only"));
+        }
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let input = match resolve_workspace_file(&self.security, input_str) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let (ffmpeg, ffprobe) = match (
+            BinaryResolver::resolve(ExternalBinary::Ffmpeg),
+            BinaryResolver::resolve(ExternalBinary::Ffprobe),
+        ) {
+            (Ok(ffmpeg), Ok(ffprobe)) => (ffmpeg, ffprobe),
+            (Err(error), _) | (_, Err(error)) => return Ok(ToolResult::failure(error)),
+        };
+
+        let chapters = match Self::probe_chapters(&ffprobe, &input).await {
+            Ok(chapters) => chapters,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+        if chapters.is_empty() {
+            return Ok(ToolResult::failure(format!(
+                "{} has no chapter metadata",
+                input.display()
+            )));
+        }
+        if chapters.len() > MAX_CHAPTERS {
+            return Ok(ToolResult::failure(format!(
+                "Too many chapters: {} (limit: {MAX_CHAPTERS})",
+                chapters.len()
+            )));
+        }
+
+        let output_dir = match self.resolve_output_dir(output_dir_str, &input).await {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let outputs: Vec<PathBuf> = chapters
+            .iter()
+            .enumerate()
+            .map(|(index, chapter)| {
+                Self::chapter_output_path(
+                    &input,
+                    &output_dir,
+                    index,
+                    chapters.len(),
+                    chapter.title.as_deref(),
+                )
+            })
+            .collect();
+        for output in &outputs {
+            if tokio::fs::symlink_metadata(output).await.is_ok() {
+                return Ok(ToolResult::failure(format!(
+                    "Chapter output already exists: {}",
+                    output.display()
+                )));
+            }
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let mut clips = Vec::with_capacity(chapters.len());
+        for (index, (chapter, output)) in chapters.iter().zip(&outputs).enumerate() {
+            let mut cmd = tokio::process::Command::new(&ffmpeg);
+            cmd.args(Self::ffmpeg_args(&input, output, chapter));
+            if let Err(error) = run_output_command(
+                cmd,
+                Some(Duration::from_secs(FFMPEG_TIMEOUT_SECS)),
+                None,
+                output,
+                DEFAULT_STDERR_TAIL_BYTES,
+                self.audit.as_deref(),
+            )
+            .await
+            {
+                return Ok(ToolResult::failure(format!(
+                    "Chapter {} of {} failed after {} clip(s) were written to {}: {error}",
+                    index + 1,
+                    chapters.len(),
+                    clips.len(),
+                    output_dir.display()
+                )));
+            }
+            clips.push(json!({
+                "index": index + 1,
+                "title": chapter.title,
+                "start": chapter.start,
+                "end": chapter.end,
+                "path": output.display().to_string(),
+            }));
+        }
+
+        let output = json!({
+            "input": input.display().to_string(),
+            "output_dir": output_dir.display().to_string(),
+            "clips": clips,
+        });
+        Ok(ToolResult {
+            success: true,
+            output: serde_json::to_string_pretty(&output).unwrap_or_default(),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: PathBuf, autonomy: AutonomyLevel) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[test]
+    fn parse_chapters_reads_times_and_titles() {
+        let probe = json!({"chapters": [
+            {"id": 0, "start_time": "0.000000", "end_time": "61.500000", "tags": {"title": " Intro "}},
+            {"id": 1, "start_time": "61.500000", "end_time": "120.000000", "tags": {}}
+        ]});
+        let chapters = MediaChapterSplitTool::parse_chapters(&probe).unwrap();
+        assert_eq!(
+            chapters,
+            vec![
+                Chapter {
+                    start: 0.0,
+                    end: 61.5,
+                    title: Some("Intro".into())
+                },
+                Chapter {
+                    start: 61.5,
+                    end: 120.0,
+                    title: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_chapters_handles_missing_and_invalid_entries() {
+        assert!(MediaChapterSplitTool::parse_chapters(&json!({}))
+            .unwrap()
+            .is_empty());
+        let bad = json!({"chapters": [{"start_time": "5.0", "end_time": "5.0"}]});
+        assert!(MediaChapterSplitTool::parse_chapters(&bad).is_err());
+    }
+
+    #[test]
+    fn sanitize_title_strips_path_separators_and_control_chars() {
+        assert_eq!(
+            MediaChapterSplitTool::sanitize_title("Q&A: what/why?"),
+            "Q_A_ what_why_"
+        );
+        assert_eq!(MediaChapterSplitTool::sanitize_title(".."), "chapter");
+        assert_eq!(
+            MediaChapterSplitTool::sanitize_title("Café intro"),
+            "Café intro"
+        );
+    }
+
+    #[test]
+    fn chapter_output_path_pads_index_and_keeps_extension() {
+        let path = MediaChapterSplitTool::chapter_output_path(
+            Path::new("/w/talk.mkv"),
+            Path::new("/w/out"),
+            2,
+            120,
+            Some("Demo"),
+        );
+        assert_eq!(path, PathBuf::from("/w/out/003 - Demo.mkv"));
+
+        let path = MediaChapterSplitTool::chapter_output_path(
+            Path::new("/w/talk.mp3"),
+            Path::new("/w/out"),
+            0,
+            3,
+            None,
+        );
+        assert_eq!(path, PathBuf::from("/w/out/01 - Chapter 1.mp3"));
+    }
+
+    #[test]
+    fn ffmpeg_args_stream_copy_the_chapter_range() {
+        let chapter = Chapter {
+            start: 61.5,
+            end: 120.0,
+            title: None,
+        };
+        let args = MediaChapterSplitTool::ffmpeg_args(
+            Path::new("/w/in.mp4"),
+            Path::new("/w/out/02.mp4"),
+            &chapter,
+        );
+        assert_eq!(
+            args,
+            vec![
+                "-hide_banner",
+                "-loglevel",
+                "error",
+                "-n",
+                "-ss",
+                "61.500",
+                "-i",
+                "/w/in.mp4",
+                "-t",
+                "58.500",
+                "-c",
+                "copy",
+                "-avoid_negative_ts",
+                "make_zero",
+                "/w/out/02.mp4"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn blocks_in_read_only_mode() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaChapterSplitTool::new(test_security(
+            tmp.path().to_path_buf(),
+            AutonomyLevel::ReadOnly,
+        ));
+        let result = tool.execute(json!({"input": "a.mp4"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_input() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaChapterSplitTool::new(test_security(
+            tmp.path().to_path_buf(),
+            AutonomyLevel::Supervised,
+        ));
+        let result = tool.execute(json!({"input": "nope.mp4"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("File not found"));
+    }
+
+    #[tokio::test]
+    async fn default_output_dir_is_created_next_to_input() {
+        let tmp = tempfile::tempdir().unwrap();
+        let workspace = tmp.path().canonicalize().unwrap();
+        let security = test_security(workspace.clone(), AutonomyLevel::Supervised);
+        assert!(security.workspace_only);
+        let tool = MediaChapterSplitTool::new(security);
+
+        let dir = tool
+            .resolve_output_dir(None, &workspace.join("talk.mp4"))
+            .await
+            .unwrap();
+        assert_eq!(dir, workspace.join("talk_chapters"));
+        assert!(dir.is_dir());
+    }
+
+    #[tokio::test]
+    async fn explicit_output_dir_outside_workspace_is_rejected() {
+        let tmp = tempfile::tempdir().unwrap();
+        let workspace = tmp.path().canonicalize().unwrap();
+        let tool =
+            MediaChapterSplitTool::new(test_security(workspace.clone(), AutonomyLevel::Supervised));
+
+        let err = tool
+            .resolve_output_dir(Some("/etc/zeroclaw_chapters"), &workspace.join("talk.mp4"))
+            .await
+            .unwrap_err();
+        assert!(err.contains("Path not allowed"));
+    }
+}
@@ -0,0 +1,390 @@
+use super::binary_resolver::{BinaryResolver, ExternalBinary};
+use super::command_runner::{run_output_command, DEFAULT_STDERR_TAIL_BYTES};
+use super::traits::{Tool, ToolResult};
+use super::workspace_file::resolve_workspace_file;
+use crate::security::{AuditLogger, SecurityPolicy};
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum time to wait for a single ffmpeg invocation.
+const FFMPEG_TIMEOUT_SECS: u64 = 600;
+
+/// Cut a clip out of a media file that already exists in the workspace.
+///
+/// Shells out to `ffmpeg`. With `reencode = false` the streams are copied,
+/// which is fast but snaps the start to the nearest keyframe; `reencode = true`
+/// decodes and re-encodes for frame-accurate cuts.
+pub struct MediaClipTool {
+    security: Arc<SecurityPolicy>,
+    audit: Option<Arc<AuditLogger>>,
+}
+
+impl MediaClipTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self {
+            security,
+            audit: None,
+        }
+    }
+
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Parse `SS[.ms]`, `MM:SS[.ms]` or `HH:MM:SS[.ms]` into seconds.
+    fn parse_timestamp(raw: &str) -> Option<f64> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return None;
+        }
+        let parts: Vec<&str> = raw.split(':').collect();
+        if parts.len() > 3 {
+            return None;
+        }
+
+        let mut seconds = 0.0_f64;
+        for (idx, part) in parts.iter().enumerate() {
+            let is_last = idx + 1 == parts.len();
+            if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit() || c == '.') {
+                return None;
+            }
+            // Only the trailing seconds component may carry a fraction.
+            if !is_last && part.contains('.') {
+                return None;
+            }
+            let value: f64 = part.parse().ok()?;
+            if idx > 0 && value >= 60.0 {
+                return None;
+            }
+            seconds = seconds * 60.0 + value;
+        }
+
+        seconds.is_finite().then_some(seconds)
+    }
+
+    /// Format seconds as an ffmpeg-friendly timestamp with millisecond precision.
+    fn format_seconds(seconds: f64) -> String {
+        format!("{seconds:.3}")
+    }
+
+    /// Build the clip path next to the input: `<stem>_clip_<start>-<end>.<ext>`.
+    fn clip_output_path(input: &Path, start: f64, end: f64) -> PathBuf {
+        let stem = input
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("media");
+        let label = |v: f64| Self::format_seconds(v).replace('.', "_");
+        let name = match input.extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{stem}_clip_{}-{}.{ext}", label(start), label(end)),
+            None => format!("{stem}_clip_{}-{}", label(start), label(end)),
+        };
+        input.with_file_name(name)
+    }
+
+    fn ffmpeg_args(
+        input: &Path,
+        output: &Path,
+        start: f64,
+        duration: f64,
+        reencode: bool,
+    ) -> Vec<String> {
+        let mut args = vec![
+            "-hide_banner".to_string(),
+            "-loglevel".into(),
+            "error".into(),
+            "-n".into(),
+            "-ss".into(),
+            Self::format_seconds(start),
+            "-i".into(),
+            input.to_string_lossy().into_owned(),
+            "-t".into(),
+            Self::format_seconds(duration),
+        ];
+        if !reencode {
+            args.extend([
+                "-c".into(),
+                "copy".into(),
+                "-avoid_negative_ts".into(),
+                "make_zero".into(),
+            ]);
+        }
+        args.push(output.to_string_lossy().into_owned());
+        args
+    }
+}
+
+#[async_trait]
+impl Tool for MediaClipTool {
+    fn name(&self) -> &str {
+        "media_clip"
+    }
+
+    fn description(&self) -> &str {
+        "Cut a clip from a local audio/video file in the workspace using ffmpeg. Returns the clip path."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "input": {
+                    "type": "string",
+                    "description": "Path to the source media file (absolute or relative to workspace)"
+                },
+                "start": {
+                    "type": "string",
+                    "description": "Clip start as seconds (e.g. '90.5') or HH:MM:SS[.ms]"
+                },
+                "end": {
+                    "type": "string",
+                    "description": "Clip end as seconds or HH:MM:SS[.ms]. Provide either 'end' or 'duration'."
+                },
+                "duration": {
+                    "type": "string",
+                    "description": "Clip length as seconds or HH:MM:SS[.ms]. Provide either 'end' or 'duration'."
+                },
+                "reencode": {
+                    "type": "boolean",
+                    "description": "Re-encode for frame-accurate cuts (slower). Default false: stream copy, cut snaps to keyframes."
+                }
+            },
+            "required": ["input", "start"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let input_str = args
+            .get("input")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'input' parameter"))?;
+        let start_str = args
+            .get("start")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'start' parameter"))?;
+        let end_str = args.get("end").and_then(|v| v.as_str());
+        let duration_str = args.get("duration").and_then(|v| v.as_str());
+        let reencode = args
+            .get("reencode")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        let Some(start) = Self::parse_timestamp(start_str) else {
+            return Ok(ToolResult::failure(format!(
+                "Invalid 'start' timestamp: {start_str}"
+            )));
+        };
+
+        let end = match (end_str, duration_str) {
+            (Some(_), Some(_)) => {
+                return Ok(ToolResult::failure(
+                    "Provide either 'end' or 'duration', not both",
+                ));
+            }
+            (None, None) => {
+                return Ok(ToolResult::failure("Missing 'end' or 'duration' parameter"));
+            }
+            (Some(raw), None) => match Self::parse_timestamp(raw) {
+                Some(end) => end,
+                None => {
+                    return Ok(ToolResult::failure(format!(
+                        "Invalid 'end' timestamp: {raw}"
+                    )))
+                }
+            },
+            (None, Some(raw)) => match Self::parse_timestamp(raw) {
+                Some(duration) => start + duration,
+                None => {
+                    return Ok(ToolResult::failure(format!(
+                        "Invalid 'duration' value: {raw}"
+                    )));
+                }
+            },
+        };
+
+        if end <= start {
+            return Ok(ToolResult::failure("Clip end must be after start"));
+        }
+
+        if !self.security.can_act() {
+            return Ok(ToolResult::failure("Action blocked: autonomy is read-only"));
+        }
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let input = match resolve_workspace_file(&self.security, input_str) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let output = Self::clip_output_path(&input, start, end);
+        if tokio::fs::symlink_metadata(&output).await.is_ok() {
+            return Ok(ToolResult::failure(format!(
+                "Clip output already exists: {}",
+                output.display()
+            )));
+        }
+
+        let ffmpeg = match BinaryResolver::resolve(ExternalBinary::Ffmpeg) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let mut cmd = tokio::process::Command::new(&ffmpeg);
+        cmd.args(Self::ffmpeg_args(
+            &input,
+            &output,
+            start,
+            end - start,
+            reencode,
+        ));
+        if let Err(error) = run_output_command(
+            cmd,
+            Some(Duration::from_secs(FFMPEG_TIMEOUT_SECS)),
+            None,
+            &output,
+            DEFAULT_STDERR_TAIL_BYTES,
+            self.audit.as_deref(),
+        )
+        .await
+        {
+            return Ok(ToolResult::failure(error));
+        }
+
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "Clip saved to: {}\nStart: {}s\nEnd: {}s\nMode: {}",
+                output.display(),
+                Self::format_seconds(start),
+                Self::format_seconds(end),
+                if reencode { "reencode" } else { "stream copy" },
+            ),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Full,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[test]
+    fn media_clip_tool_name_and_schema() {
+        let tool = MediaClipTool::new(test_security(std::env::temp_dir()));
+        assert_eq!(tool.name(), "media_clip");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["input"].is_object());
+        assert!(schema["properties"]["reencode"].is_object());
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&json!("input")));
+        assert!(required.contains(&json!("start")));
+    }
+
+    #[test]
+    fn parse_timestamp_accepts_seconds_and_clock_formats() {
+        assert_eq!(MediaClipTool::parse_timestamp("90"), Some(90.0));
+        assert_eq!(MediaClipTool::parse_timestamp("12.5"), Some(12.5));
+        assert_eq!(MediaClipTool::parse_timestamp("01:30"), Some(90.0));
+        assert_eq!(MediaClipTool::parse_timestamp("1:02:03.5"), Some(3723.5));
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_malformed_values() {
+        assert_eq!(MediaClipTool::parse_timestamp(""), None);
+        assert_eq!(MediaClipTool::parse_timestamp("-5"), None);
+        assert_eq!(MediaClipTool::parse_timestamp("1:75"), None);
+        assert_eq!(MediaClipTool::parse_timestamp("1.5:00"), None);
+        assert_eq!(MediaClipTool::parse_timestamp("1:2:3:4"), None);
+        assert_eq!(MediaClipTool::parse_timestamp("abc"), None);
+    }
+
+    #[test]
+    fn ffmpeg_args_stream_copy_vs_reencode() {
+        let input = Path::new("/ws/in.mp4");
+        let output = Path::new("/ws/out.mp4");
+
+        let copy = MediaClipTool::ffmpeg_args(input, output, 5.0, 10.0, false);
+        assert!(copy.windows(2).any(|w| w == ["-c", "copy"]));
+        assert!(copy.windows(2).any(|w| w == ["-ss", "5.000"]));
+        assert!(copy.windows(2).any(|w| w == ["-t", "10.000"]));
+        assert_eq!(copy.last().map(String::as_str), Some("/ws/out.mp4"));
+
+        let reencode = MediaClipTool::ffmpeg_args(input, output, 5.0, 10.0, true);
+        assert!(!reencode.iter().any(|a| a == "copy"));
+    }
+
+    #[test]
+    fn clip_output_path_keeps_extension_and_directory() {
+        let path = MediaClipTool::clip_output_path(Path::new("/ws/talk.mkv"), 1.5, 3.0);
+        assert_eq!(path, PathBuf::from("/ws/talk_clip_1_500-3_000.mkv"));
+    }
+
+    #[tokio::test]
+    async fn rejects_end_before_start() {
+        let tool = MediaClipTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({"input": "in.mp4", "start": "10", "end": "5"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("after start"));
+    }
+
+    #[tokio::test]
+    async fn rejects_end_and_duration_together() {
+        let tool = MediaClipTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({"input": "in.mp4", "start": "0", "end": "5", "duration": "5"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("not both"));
+    }
+
+    #[tokio::test]
+    async fn blocks_input_outside_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaClipTool::new(test_security(tmp.path().to_path_buf()));
+        let result = tool
+            .execute(json!({"input": "/etc/passwd", "start": "0", "duration": "1"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn blocks_in_read_only_mode() {
+        let tool = MediaClipTool::new(Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        }));
+        let result = tool
+            .execute(json!({"input": "in.mp4", "start": "0", "duration": "1"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+}
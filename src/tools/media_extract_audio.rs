@@ -0,0 +1,623 @@
+use super::binary_resolver::{BinaryResolver, ExternalBinary};
+use super::command_runner::{run_command, run_output_command, DEFAULT_STDERR_TAIL_BYTES};
+use super::traits::{Tool, ToolResult};
+use super::workspace_file::resolve_workspace_file;
+use crate::security::{AuditLogger, SecurityPolicy};
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum time to wait for a single ffmpeg invocation.
+const FFMPEG_TIMEOUT_SECS: u64 = 600;
+const DEFAULT_AUDIO_FORMAT: &str = "mp3";
+const DEFAULT_AUDIO_BITRATE: &str = "192k";
+/// Accepted `audio_bitrate` range, in kbit/s.
+const BITRATE_KBPS_RANGE: std::ops::RangeInclusive<u32> = 8..=512;
+/// Default integrated loudness target (podcast/streaming norm), in LUFS.
+const DEFAULT_TARGET_LUFS: f64 = -16.0;
+/// Default true-peak ceiling, in dBTP.
+const DEFAULT_TRUE_PEAK: f64 = -1.5;
+/// Loudness range target passed to `loudnorm`, in LU.
+const LOUDNORM_LRA: f64 = 11.0;
+/// `loudnorm` upsamples internally; resample the result to a common rate.
+const NORMALIZED_SAMPLE_RATE: &str = "48000";
+
+/// Input loudness measured by a first `loudnorm` pass.
+#[derive(Debug, Clone, PartialEq)]
+struct LoudnessMeasurement {
+    input_i: f64,
+    input_tp: f64,
+    input_lra: f64,
+    input_thresh: f64,
+    target_offset: f64,
+}
+
+/// Extract the audio track of a local media file in the workspace.
+///
+/// Shells out to `ffmpeg`, dropping video and encoding the first audio stream
+/// to `<stem>_audio.<format>` next to the input. With `normalize`, a two-pass
+/// EBU R128 `loudnorm` brings the result to `target_lufs` with peaks limited
+/// to `true_peak`.
+pub struct MediaExtractAudioTool {
+    security: Arc<SecurityPolicy>,
+    audit: Option<Arc<AuditLogger>>,
+}
+
+impl MediaExtractAudioTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self {
+            security,
+            audit: None,
+        }
+    }
+
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// ffmpeg encoder for `format`, and whether it takes a bitrate.
+    fn codec(format: &str) -> Option<(&'static str, bool)> {
+        match format {
+            "mp3" => Some(("libmp3lame", true)),
+            "m4a" => Some(("aac", true)),
+            "opus" => Some(("libopus", true)),
+            "wav" => Some(("pcm_s16le", false)),
+            "flac" => Some(("flac", false)),
+            _ => None,
+        }
+    }
+
+    /// Parse a bitrate like `192k` (or bare `192`) into kbit/s.
+    fn parse_bitrate(raw: &str) -> Option<u32> {
+        let digits = raw.trim().strip_suffix(['k', 'K']).unwrap_or(raw.trim());
+        digits
+            .parse::<u32>()
+            .ok()
+            .filter(|kbps| BITRATE_KBPS_RANGE.contains(kbps))
+    }
+
+    /// Build the output path next to the input: `<stem>_audio.<format>`.
+    fn audio_output_path(input: &Path, format: &str) -> PathBuf {
+        let stem = input
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("media");
+        input.with_file_name(format!("{stem}_audio.{format}"))
+    }
+
+    /// Build the `loudnorm` filter; `measured` switches it to the linear second pass.
+    fn loudnorm_filter(
+        target_lufs: f64,
+        true_peak: f64,
+        measured: Option<&LoudnessMeasurement>,
+    ) -> String {
+        let base = format!("loudnorm=I={target_lufs}:TP={true_peak}:LRA={LOUDNORM_LRA}");
+        match measured {
+            Some(m) => format!(
+                "{base}:measured_I={}:measured_TP={}:measured_LRA={}:measured_thresh={}:offset={}:linear=true",
+                m.input_i, m.input_tp, m.input_lra, m.input_thresh, m.target_offset
+            ),
+            None => format!("{base}:print_format=json"),
+        }
+    }
+
+    /// First pass: decode the audio through `loudnorm` and discard the output.
+    fn measure_args(input: &Path, filter: &str) -> Vec<String> {
+        vec![
+            "-hide_banner".to_string(),
+            "-nostats".into(),
+            "-i".into(),
+            input.to_string_lossy().into_owned(),
+            "-map".into(),
+            "0:a:0".into(),
+            "-af".into(),
+            filter.into(),
+            "-f".into(),
+            "null".into(),
+            "-".into(),
+        ]
+    }
+
+    /// Parse the JSON block `loudnorm=print_format=json` writes at the end of stderr.
+    fn parse_loudness_measurement(stderr: &str) -> Option<LoudnessMeasurement> {
+        let start = stderr.rfind('{')?;
+        let end = start + stderr[start..].find('}')?;
+        let stats: serde_json::Value = serde_json::from_str(&stderr[start..=end]).ok()?;
+        // loudnorm reports every value as a string.
+        let value = |key: &str| stats.get(key)?.as_str()?.trim().parse::<f64>().ok();
+        Some(LoudnessMeasurement {
+            input_i: value("input_i")?,
+            input_tp: value("input_tp")?,
+            input_lra: value("input_lra")?,
+            input_thresh: value("input_thresh")?,
+            target_offset: value("target_offset")?,
+        })
+    }
+
+    /// Read an optional number argument, checking it against `range`.
+    fn number_arg(
+        args: &serde_json::Value,
+        key: &str,
+        default: f64,
+        range: std::ops::RangeInclusive<f64>,
+    ) -> Result<f64, String> {
+        match args.get(key) {
+            None => Ok(default),
+            Some(value) => value.as_f64().filter(|v| range.contains(v)).ok_or_else(|| {
+                format!(
+                    "'{key}' must be a number between {} and {}",
+                    range.start(),
+                    range.end()
+                )
+            }),
+        }
+    }
+
+    fn ffmpeg_args(
+        input: &Path,
+        output: &Path,
+        codec: &str,
+        bitrate_kbps: Option<u32>,
+        filter: Option<&str>,
+    ) -> Vec<String> {
+        let mut args = vec![
+            "-hide_banner".to_string(),
+            "-loglevel".into(),
+            "error".into(),
+            "-n".into(),
+            "-i".into(),
+            input.to_string_lossy().into_owned(),
+            "-map".into(),
+            "0:a:0".into(),
+            "-vn".into(),
+        ];
+        if let Some(filter) = filter {
+            args.extend([
+                "-af".into(),
+                filter.into(),
+                "-ar".into(),
+                NORMALIZED_SAMPLE_RATE.into(),
+            ]);
+        }
+        args.extend(["-c:a".into(), codec.into()]);
+        if let Some(kbps) = bitrate_kbps {
+            args.extend(["-b:a".into(), format!("{kbps}k")]);
+        }
+        args.push(output.to_string_lossy().into_owned());
+        args
+    }
+}
+
+#[async_trait]
+impl Tool for MediaExtractAudioTool {
+    fn name(&self) -> &str {
+        "media_extract_audio"
+    }
+
+    fn description(&self) -> &str {
+        "Extract the audio track of a local video/audio file in the workspace using ffmpeg \
+         (mp3, m4a, opus, wav or flac), optionally loudness-normalized. Returns the audio file \
+         path and, when normalizing, the measured input loudness and applied gain."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "input": {
+                    "type": "string",
+                    "description": "Path to the source media file (absolute or relative to workspace)"
+                },
+                "audio_format": {
+                    "type": "string",
+                    "enum": ["mp3", "m4a", "opus", "wav", "flac"],
+                    "description": "Output audio format. Default: mp3",
+                    "default": DEFAULT_AUDIO_FORMAT
+                },
+                "audio_bitrate": {
+                    "type": "string",
+                    "description": "Bitrate for mp3/m4a/opus, e.g. '128k' (8k-512k). Ignored for wav/flac. Default: 192k",
+                    "default": DEFAULT_AUDIO_BITRATE
+                },
+                "normalize": {
+                    "type": "boolean",
+                    "description": "Apply two-pass EBU R128 loudness normalization (ffmpeg loudnorm). Default: false",
+                    "default": false
+                },
+                "target_lufs": {
+                    "type": "number",
+                    "minimum": -70,
+                    "maximum": -5,
+                    "description": "With normalize: integrated loudness target in LUFS. Default: -16",
+                    "default": DEFAULT_TARGET_LUFS
+                },
+                "true_peak": {
+                    "type": "number",
+                    "minimum": -9,
+                    "maximum": 0,
+                    "description": "With normalize: true-peak ceiling in dBTP. Default: -1.5",
+                    "default": DEFAULT_TRUE_PEAK
+                }
+            },
+            "required": ["input"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let input_str = args
+            .get("input")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'input' parameter"))?;
+        let format = args
+            .get("audio_format")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(DEFAULT_AUDIO_FORMAT);
+        let bitrate_str = args
+            .get("audio_bitrate")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or(DEFAULT_AUDIO_BITRATE);
+
+        let normalize = args
+            .get("normalize")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let loudness = if normalize {
+            let target_lufs =
+                match Self::number_arg(&args, "target_lufs", DEFAULT_TARGET_LUFS, -70.0..=-5.0) {
+                    Ok(v) => v,
+                    Err(error) => return Ok(ToolResult::failure(error)),
+                };
+            let true_peak =
+                match Self::number_arg(&args, "true_peak", DEFAULT_TRUE_PEAK, -9.0..=0.0) {
+                    Ok(v) => v,
+                    Err(error) => return Ok(ToolResult::failure(error)),
+                };
+            Some((target_lufs, true_peak))
+        } else {
+            None
+        };
+
+        let Some((codec, takes_bitrate)) = Self::codec(format) else {
+            return Ok(ToolResult::failure(format!(
+                "Unsupported audio_format '{format}'. Use one of: mp3, m4a, opus, wav, flac"
+            )));
+        };
+        let bitrate_kbps = if takes_bitrate {
+            match Self::parse_bitrate(bitrate_str) {
+                Some(kbps) => Some(kbps),
+                None => {
+                    return Ok(ToolResult::failure(format!(
+                        "Invalid 'audio_bitrate': {bitrate_str} (expected e.g. '192k', 8k-512k)"
+                    )));
+                }
+            }
+        } else {
+            None
+        };
+
+        if !self.security.can_act() {
+            return Ok(ToolResult::failure("Action blocked: autonomy is read-only"));
+        }
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let input = match resolve_workspace_file(&self.security, input_str) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let output = Self::audio_output_path(&input, format);
+        if tokio::fs::symlink_metadata(&output).await.is_ok() {
+            return Ok(ToolResult::failure(format!(
+                "Audio output already exists: {}",
+                output.display()
+            )));
+        }
+
+        let ffmpeg = match BinaryResolver::resolve(ExternalBinary::Ffmpeg) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let mut filter = None;
+        let mut loudness_report = String::new();
+        if let Some((target_lufs, true_peak)) = loudness {
+            let mut cmd = tokio::process::Command::new(&ffmpeg);
+            cmd.args(Self::measure_args(
+                &input,
+                &Self::loudnorm_filter(target_lufs, true_peak, None),
+            ));
+            let measured = match run_command(
+                cmd,
+                Some(Duration::from_secs(FFMPEG_TIMEOUT_SECS)),
+                None,
+            )
+            .await
+            {
+                Ok(out) if out.success() => Self::parse_loudness_measurement(&out.stderr),
+                Ok(out) => {
+                    return Ok(ToolResult::failure(out.failure_message(
+                        "ffmpeg loudness measurement",
+                        DEFAULT_STDERR_TAIL_BYTES,
+                    )));
+                }
+                Err(error) => return Ok(ToolResult::failure(error.to_string())),
+            };
+            let Some(measured) = measured else {
+                return Ok(ToolResult::failure(
+                    "Loudness measurement failed: ffmpeg printed no loudnorm stats",
+                ));
+            };
+            if !measured.input_i.is_finite() {
+                return Ok(ToolResult::failure(format!(
+                    "{} has no audible audio to normalize",
+                    input.display()
+                )));
+            }
+            loudness_report = format!(
+                "\nInput loudness: {:.1} LUFS (true peak {:.1} dBTP)\nTarget: {target_lufs:.1} LUFS, true peak {true_peak:.1} dBTP\nApplied gain: {:+.1} dB",
+                measured.input_i,
+                measured.input_tp,
+                target_lufs - measured.input_i,
+            );
+            filter = Some(Self::loudnorm_filter(
+                target_lufs,
+                true_peak,
+                Some(&measured),
+            ));
+        }
+
+        let mut cmd = tokio::process::Command::new(&ffmpeg);
+        cmd.args(Self::ffmpeg_args(
+            &input,
+            &output,
+            codec,
+            bitrate_kbps,
+            filter.as_deref(),
+        ));
+        if let Err(error) = run_output_command(
+            cmd,
+            Some(Duration::from_secs(FFMPEG_TIMEOUT_SECS)),
+            None,
+            &output,
+            DEFAULT_STDERR_TAIL_BYTES,
+            self.audit.as_deref(),
+        )
+        .await
+        {
+            return Ok(ToolResult::failure(error));
+        }
+
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "Audio saved to: {}\nFormat: {format}{}{loudness_report}",
+                output.display(),
+                bitrate_kbps.map_or_else(String::new, |kbps| format!("\nBitrate: {kbps}k")),
+            ),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: PathBuf, autonomy: AutonomyLevel) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[test]
+    fn media_extract_audio_tool_name_and_schema() {
+        let tool =
+            MediaExtractAudioTool::new(test_security(std::env::temp_dir(), AutonomyLevel::Full));
+        assert_eq!(tool.name(), "media_extract_audio");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["audio_format"].is_object());
+        assert_eq!(schema["required"], json!(["input"]));
+    }
+
+    #[test]
+    fn parse_bitrate_accepts_kbps_within_range() {
+        assert_eq!(MediaExtractAudioTool::parse_bitrate("192k"), Some(192));
+        assert_eq!(MediaExtractAudioTool::parse_bitrate(" 128K "), Some(128));
+        assert_eq!(MediaExtractAudioTool::parse_bitrate("320"), Some(320));
+        assert_eq!(MediaExtractAudioTool::parse_bitrate("4k"), None);
+        assert_eq!(MediaExtractAudioTool::parse_bitrate("1024k"), None);
+        assert_eq!(MediaExtractAudioTool::parse_bitrate("fast"), None);
+    }
+
+    #[test]
+    fn output_path_sits_next_to_input() {
+        let output =
+            MediaExtractAudioTool::audio_output_path(Path::new("/w/talks/keynote.mp4"), "mp3");
+        assert_eq!(output, PathBuf::from("/w/talks/keynote_audio.mp3"));
+    }
+
+    #[test]
+    fn ffmpeg_args_drop_video_and_set_bitrate_only_for_lossy_formats() {
+        let args = MediaExtractAudioTool::ffmpeg_args(
+            Path::new("/w/a.mp4"),
+            Path::new("/w/a_audio.mp3"),
+            "libmp3lame",
+            Some(192),
+            None,
+        );
+        assert_eq!(
+            args,
+            vec![
+                "-hide_banner",
+                "-loglevel",
+                "error",
+                "-n",
+                "-i",
+                "/w/a.mp4",
+                "-map",
+                "0:a:0",
+                "-vn",
+                "-c:a",
+                "libmp3lame",
+                "-b:a",
+                "192k",
+                "/w/a_audio.mp3"
+            ]
+        );
+
+        let args = MediaExtractAudioTool::ffmpeg_args(
+            Path::new("/w/a.mp4"),
+            Path::new("/w/a_audio.flac"),
+            "flac",
+            None,
+            None,
+        );
+        assert!(!args.iter().any(|arg| arg == "-b:a"));
+    }
+
+    #[test]
+    fn loudnorm_filter_switches_to_linear_second_pass_with_measurement() {
+        assert_eq!(
+            MediaExtractAudioTool::loudnorm_filter(-16.0, -1.5, None),
+            "loudnorm=I=-16:TP=-1.5:LRA=11:print_format=json"
+        );
+        let measured = LoudnessMeasurement {
+            input_i: -27.2,
+            input_tp: -9.8,
+            input_lra: 6.4,
+            input_thresh: -37.6,
+            target_offset: 0.3,
+        };
+        assert_eq!(
+            MediaExtractAudioTool::loudnorm_filter(-14.0, -1.0, Some(&measured)),
+            "loudnorm=I=-14:TP=-1:LRA=11:measured_I=-27.2:measured_TP=-9.8:measured_LRA=6.4:measured_thresh=-37.6:offset=0.3:linear=true"
+        );
+
+        let args = MediaExtractAudioTool::ffmpeg_args(
+            Path::new("/w/a.mp4"),
+            Path::new("/w/a_audio.flac"),
+            "flac",
+            None,
+            Some("loudnorm=I=-16"),
+        );
+        let af = args.iter().position(|arg| arg == "-af").unwrap();
+        assert_eq!(args[af + 1], "loudnorm=I=-16");
+        assert!(af < args.iter().position(|arg| arg == "-c:a").unwrap());
+        assert!(args.iter().any(|arg| arg == "48000"));
+    }
+
+    #[test]
+    fn parses_loudnorm_stats_from_stderr() {
+        let stderr = r#"Input #0, mov,mp4,m4a,3gp,3g2,mj2, from 'a.mp4':
+[Parsed_loudnorm_0 @ 0x600000b8c000]
+{
+	"input_i" : "-27.20",
+	"input_tp" : "-9.81",
+	"input_lra" : "6.40",
+	"input_thresh" : "-37.61",
+	"output_i" : "-16.04",
+	"output_tp" : "-1.50",
+	"output_lra" : "5.10",
+	"output_thresh" : "-26.40",
+	"normalization_type" : "dynamic",
+	"target_offset" : "0.04"
+}
+"#;
+        let measured = MediaExtractAudioTool::parse_loudness_measurement(stderr).unwrap();
+        assert_eq!(
+            measured,
+            LoudnessMeasurement {
+                input_i: -27.2,
+                input_tp: -9.81,
+                input_lra: 6.4,
+                input_thresh: -37.61,
+                target_offset: 0.04,
+            }
+        );
+
+        let silent = stderr.replace("\"-27.20\"", "\"-inf\"");
+        let measured = MediaExtractAudioTool::parse_loudness_measurement(&silent).unwrap();
+        assert!(!measured.input_i.is_finite());
+
+        assert!(MediaExtractAudioTool::parse_loudness_measurement("no stats here").is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_format_and_bad_bitrate() {
+        let tool =
+            MediaExtractAudioTool::new(test_security(std::env::temp_dir(), AutonomyLevel::Full));
+        let result = tool
+            .execute(json!({"input": "a.mp4", "audio_format": "aiff"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("Unsupported audio_format"));
+
+        let result = tool
+            .execute(json!({"input": "a.mp4", "audio_bitrate": "huge"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("Invalid 'audio_bitrate'"));
+
+        // Lossless formats ignore the bitrate entirely.
+        let result = tool
+            .execute(json!({"input": "nope.mp4", "audio_format": "wav", "audio_bitrate": "huge"}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("File not found"));
+
+        let result = tool
+            .execute(json!({"input": "a.mp4", "normalize": true, "target_lufs": 3}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("'target_lufs' must be"));
+
+        // Loudness options are only checked when normalizing.
+        let result = tool
+            .execute(json!({"input": "nope.mp4", "true_peak": 3}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("File not found"));
+    }
+
+    #[tokio::test]
+    async fn blocks_read_only_autonomy() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaExtractAudioTool::new(test_security(
+            tmp.path().to_path_buf(),
+            AutonomyLevel::ReadOnly,
+        ));
+        let result = tool.execute(json!({"input": "a.mp4"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+
+    #[tokio::test]
+    async fn refuses_to_overwrite_existing_output() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("a.mp4"), b"video").unwrap();
+        std::fs::write(tmp.path().join("a_audio.mp3"), b"audio").unwrap();
+        let tool = MediaExtractAudioTool::new(test_security(
+            tmp.path().to_path_buf(),
+            AutonomyLevel::Full,
+        ));
+        let result = tool.execute(json!({"input": "a.mp4"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("already exists"));
+    }
+}
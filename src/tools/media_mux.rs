@@ -0,0 +1,344 @@
+use super::binary_resolver::{BinaryResolver, ExternalBinary};
+use super::command_runner::{run_command, run_output_command, DEFAULT_STDERR_TAIL_BYTES};
+use super::traits::{Tool, ToolResult};
+use super::workspace_file::resolve_workspace_file;
+use crate::security::{AuditLogger, SecurityPolicy};
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum time to wait for the ffmpeg mux step.
+const FFMPEG_TIMEOUT_SECS: u64 = 600;
+/// Maximum time to wait for an ffprobe stream listing.
+const FFPROBE_TIMEOUT_SECS: u64 = 30;
+
+/// Replace the audio track of a local video with a separately provided audio file.
+///
+/// Both inputs are validated with `ffprobe` (the video must carry a video
+/// stream, the audio file an audio stream), then muxed with
+/// `ffmpeg -c:v copy -map 0:v -map 1:a` so the video is never re-encoded.
+pub struct MediaMuxTool {
+    security: Arc<SecurityPolicy>,
+    audit: Option<Arc<AuditLogger>>,
+}
+
+impl MediaMuxTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self {
+            security,
+            audit: None,
+        }
+    }
+
+    pub fn with_audit(mut self, audit: Option<Arc<AuditLogger>>) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// Resolve (and create) the output directory, keeping it inside the workspace.
+    async fn resolve_output_dir(&self, dir_str: &str) -> Result<PathBuf, String> {
+        if !self.security.is_path_allowed(dir_str) {
+            return Err(format!(
+                "Path not allowed: {dir_str} (must be within workspace)"
+            ));
+        }
+
+        let raw_path = Path::new(dir_str);
+        let candidate = if raw_path.is_absolute() {
+            raw_path.to_path_buf()
+        } else {
+            self.security.workspace_dir.join(raw_path)
+        };
+
+        tokio::fs::create_dir_all(&candidate)
+            .await
+            .map_err(|e| format!("Failed to create output directory: {e}"))?;
+        let resolved = tokio::fs::canonicalize(&candidate)
+            .await
+            .map_err(|e| format!("Failed to resolve output directory: {e}"))?;
+
+        if !self.security.is_resolved_path_allowed(&resolved) {
+            return Err(self.security.resolved_path_violation_message(&resolved));
+        }
+
+        Ok(resolved)
+    }
+
+    /// Build `<video_stem>_muxed.<video_ext>` inside `dir`.
+    fn muxed_output_path(video: &Path, dir: &Path) -> PathBuf {
+        let stem = video
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("video");
+        let name = match video.extension().and_then(|e| e.to_str()) {
+            Some(ext) => format!("{stem}_muxed.{ext}"),
+            None => format!("{stem}_muxed.mp4"),
+        };
+        dir.join(name)
+    }
+
+    fn ffmpeg_args(video: &Path, audio: &Path, output: &Path) -> Vec<String> {
+        vec![
+            "-hide_banner".to_string(),
+            "-loglevel".into(),
+            "error".into(),
+            "-n".into(),
+            "-i".into(),
+            video.to_string_lossy().into_owned(),
+            "-i".into(),
+            audio.to_string_lossy().into_owned(),
+            "-c:v".into(),
+            "copy".into(),
+            "-map".into(),
+            "0:v".into(),
+            "-map".into(),
+            "1:a".into(),
+            output.to_string_lossy().into_owned(),
+        ]
+    }
+
+    /// Parse `ffprobe -show_entries stream=codec_type -of csv=p=0` output.
+    fn has_stream_type(ffprobe_stdout: &str, codec_type: &str) -> bool {
+        ffprobe_stdout
+            .lines()
+            .any(|line| line.trim().trim_end_matches(',') == codec_type)
+    }
+
+    /// Check that `path` contains at least one stream of `codec_type`.
+    async fn probe_stream_type(
+        ffprobe: &Path,
+        path: &Path,
+        codec_type: &str,
+    ) -> Result<(), String> {
+        let mut cmd = tokio::process::Command::new(ffprobe);
+        cmd.args(["-v", "error", "-show_entries", "stream=codec_type"])
+            .args(["-of", "csv=p=0"])
+            .arg(path);
+
+        match run_command(cmd, Some(Duration::from_secs(FFPROBE_TIMEOUT_SECS)), None).await {
+            Ok(out) if out.success() => {
+                if Self::has_stream_type(&out.stdout, codec_type) {
+                    Ok(())
+                } else {
+                    Err(format!("{} has no {codec_type} stream", path.display()))
+                }
+            }
+            Ok(out) => Err(format!(
+                "ffprobe could not read {}: {}",
+                path.display(),
+                out.stderr_tail(DEFAULT_STDERR_TAIL_BYTES)
+            )),
+            Err(error) => Err(error.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for MediaMuxTool {
+    fn name(&self) -> &str {
+        "media_mux"
+    }
+
+    fn description(&self) -> &str {
+        "Replace the audio track of a local video with a separate audio file using ffmpeg (video is stream-copied). Returns the muxed file path."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "video_input": {
+                    "type": "string",
+                    "description": "Path to the source video (absolute or relative to workspace)"
+                },
+                "audio_input": {
+                    "type": "string",
+                    "description": "Path to the replacement audio file (absolute or relative to workspace)"
+                },
+                "output_dir": {
+                    "type": "string",
+                    "description": "Directory for the muxed file (default: the video's directory). Must be within workspace."
+                }
+            },
+            "required": ["video_input", "audio_input"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let video_str = args
+            .get("video_input")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'video_input' parameter"))?;
+        let audio_str = args
+            .get("audio_input")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'audio_input' parameter"))?;
+        let output_dir_str = args.get("output_dir").and_then(|v| v.as_str());
+
+        if !self.security.can_act() {
+            return Ok(ToolResult::failure("Action blocked: autonomy is read-only"));
+        }
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let video = match resolve_workspace_file(&self.security, video_str) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+        let audio = match resolve_workspace_file(&self.security, audio_str) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let (ffmpeg, ffprobe) = match (
+            BinaryResolver::resolve(ExternalBinary::Ffmpeg),
+            BinaryResolver::resolve(ExternalBinary::Ffprobe),
+        ) {
+            (Ok(ffmpeg), Ok(ffprobe)) => (ffmpeg, ffprobe),
+            (Err(error), _) | (_, Err(error)) => return Ok(ToolResult::failure(error)),
+        };
+
+        if let Err(error) = Self::probe_stream_type(&ffprobe, &video, "video").await {
+            return Ok(ToolResult::failure(error));
+        }
+        if let Err(error) = Self::probe_stream_type(&ffprobe, &audio, "audio").await {
+            return Ok(ToolResult::failure(error));
+        }
+
+        let output_dir = match output_dir_str {
+            Some(dir) => match self.resolve_output_dir(dir).await {
+                Ok(path) => path,
+                Err(error) => return Ok(ToolResult::failure(error)),
+            },
+            None => video
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| self.security.workspace_dir.clone()),
+        };
+
+        let output = Self::muxed_output_path(&video, &output_dir);
+        if tokio::fs::symlink_metadata(&output).await.is_ok() {
+            return Ok(ToolResult::failure(format!(
+                "Muxed output already exists: {}",
+                output.display()
+            )));
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let mut cmd = tokio::process::Command::new(&ffmpeg);
+        cmd.args(Self::ffmpeg_args(&video, &audio, &output));
+        if let Err(error) = run_output_command(
+            cmd,
+            Some(Duration::from_secs(FFMPEG_TIMEOUT_SECS)),
+            None,
+            &output,
+            DEFAULT_STDERR_TAIL_BYTES,
+            self.audit.as_deref(),
+        )
+        .await
+        {
+            return Ok(ToolResult::failure(error));
+        }
+
+        Ok(ToolResult {
+            success: true,
+            output: format!(
+                "Muxed file saved to: {}\nVideo: {}\nAudio: {}",
+                output.display(),
+                video.display(),
+                audio.display(),
+            ),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+
+    fn test_security(workspace: PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Full,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    #[test]
+    fn media_mux_tool_name_and_schema() {
+        let tool = MediaMuxTool::new(test_security(std::env::temp_dir()));
+        assert_eq!(tool.name(), "media_mux");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["output_dir"].is_object());
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&json!("video_input")));
+        assert!(required.contains(&json!("audio_input")));
+    }
+
+    #[test]
+    fn ffmpeg_args_copy_video_and_map_external_audio() {
+        let args = MediaMuxTool::ffmpeg_args(
+            Path::new("/ws/v.mp4"),
+            Path::new("/ws/a.m4a"),
+            Path::new("/ws/v_muxed.mp4"),
+        );
+        assert!(args.windows(2).any(|w| w == ["-c:v", "copy"]));
+        assert!(args.windows(2).any(|w| w == ["-map", "0:v"]));
+        assert!(args.windows(2).any(|w| w == ["-map", "1:a"]));
+        assert_eq!(args.last().map(String::as_str), Some("/ws/v_muxed.mp4"));
+    }
+
+    #[test]
+    fn muxed_output_path_uses_video_stem_and_extension() {
+        let path =
+            MediaMuxTool::muxed_output_path(Path::new("/ws/in/clip.mkv"), Path::new("/ws/out"));
+        assert_eq!(path, PathBuf::from("/ws/out/clip_muxed.mkv"));
+    }
+
+    #[test]
+    fn has_stream_type_parses_ffprobe_csv() {
+        let out = "video\naudio\n";
+        assert!(MediaMuxTool::has_stream_type(out, "video"));
+        assert!(MediaMuxTool::has_stream_type(out, "audio"));
+        assert!(!MediaMuxTool::has_stream_type("audio,\n", "video"));
+        assert!(MediaMuxTool::has_stream_type("audio,\n", "audio"));
+    }
+
+    #[tokio::test]
+    async fn blocks_in_read_only_mode() {
+        let tool = MediaMuxTool::new(Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            ..SecurityPolicy::default()
+        }));
+        let result = tool
+            .execute(json!({"video_input": "v.mp4", "audio_input": "a.m4a"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("read-only"));
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_inputs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaMuxTool::new(test_security(tmp.path().to_path_buf()));
+        let result = tool
+            .execute(json!({"video_input": "missing.mp4", "audio_input": "missing.m4a"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("File not found"));
+    }
+}
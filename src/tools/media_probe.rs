@@ -0,0 +1,300 @@
+use super::binary_resolver::{BinaryResolver, ExternalBinary};
+use super::command_runner::{run_command, DEFAULT_STDERR_TAIL_BYTES};
+use super::traits::{Tool, ToolResult};
+use super::workspace_file::resolve_workspace_file;
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Maximum time to wait for ffprobe.
+const FFPROBE_TIMEOUT_SECS: u64 = 30;
+
+/// Read technical metadata (duration, codecs, resolution, bitrate, streams)
+/// from a local media file with `ffprobe`.
+///
+/// Read-only: the file is never modified, so the tool is available in
+/// read-only autonomy.
+pub struct MediaProbeTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl MediaProbeTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+
+    fn ffprobe_args(input: &Path) -> Vec<String> {
+        vec![
+            "-v".to_string(),
+            "error".into(),
+            "-show_format".into(),
+            "-show_streams".into(),
+            "-print_format".into(),
+            "json".into(),
+            input.to_string_lossy().into_owned(),
+        ]
+    }
+
+    /// ffprobe reports most numbers as strings; convert them where present.
+    fn number(value: &serde_json::Value, key: &str) -> serde_json::Value {
+        match value.get(key) {
+            Some(serde_json::Value::String(s)) => s
+                .parse::<u64>()
+                .map(serde_json::Value::from)
+                .or_else(|_| s.parse::<f64>().map(serde_json::Value::from))
+                .unwrap_or(serde_json::Value::Null),
+            Some(n @ serde_json::Value::Number(_)) => n.clone(),
+            _ => serde_json::Value::Null,
+        }
+    }
+
+    /// Reduce ffprobe's `-show_format -show_streams` JSON to the fields agents use.
+    fn summarize(probe: &serde_json::Value) -> serde_json::Value {
+        let format = probe.get("format").unwrap_or(&serde_json::Value::Null);
+        let streams: Vec<serde_json::Value> = probe
+            .get("streams")
+            .and_then(serde_json::Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|stream| {
+                let mut summary = json!({
+                    "index": stream.get("index"),
+                    "codec_type": stream.get("codec_type"),
+                    "codec_name": stream.get("codec_name"),
+                    "duration": Self::number(stream, "duration"),
+                    "bit_rate": Self::number(stream, "bit_rate"),
+                });
+                match stream.get("codec_type").and_then(serde_json::Value::as_str) {
+                    Some("video") => {
+                        summary["width"] = Self::number(stream, "width");
+                        summary["height"] = Self::number(stream, "height");
+                        summary["frame_rate"] = json!(stream.get("avg_frame_rate"));
+                    }
+                    Some("audio") => {
+                        summary["sample_rate"] = Self::number(stream, "sample_rate");
+                        summary["channels"] = Self::number(stream, "channels");
+                    }
+                    _ => {}
+                }
+                if let Some(language) = stream.pointer("/tags/language") {
+                    summary["language"] = language.clone();
+                }
+                summary
+            })
+            .collect();
+
+        json!({
+            "format_name": format.get("format_name"),
+            "duration": Self::number(format, "duration"),
+            "size": Self::number(format, "size"),
+            "bit_rate": Self::number(format, "bit_rate"),
+            "streams": streams,
+        })
+    }
+}
+
+#[async_trait]
+impl Tool for MediaProbeTool {
+    fn name(&self) -> &str {
+        "media_probe"
+    }
+
+    fn description(&self) -> &str {
+        "Read technical metadata of a local media file with ffprobe: duration, container, bitrate, \
+         and per-stream codec, resolution, frame rate, sample rate and channels. Set 'raw' for the \
+         full ffprobe JSON."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "input": {
+                    "type": "string",
+                    "description": "Path to the media file (absolute or relative to workspace)"
+                },
+                "raw": {
+                    "type": "boolean",
+                    "description": "Return ffprobe's full -show_format -show_streams JSON instead of a summary. Default: false",
+                    "default": false
+                }
+            },
+            "required": ["input"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let input_str = args
+            .get("input")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'input' parameter"))?;
+        let raw = args
+            .get("raw")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let input = match resolve_workspace_file(&self.security, input_str) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let ffprobe = match BinaryResolver::resolve(ExternalBinary::Ffprobe) {
+            Ok(path) => path,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let mut cmd = tokio::process::Command::new(&ffprobe);
+        cmd.args(Self::ffprobe_args(&input));
+        let out =
+            match run_command(cmd, Some(Duration::from_secs(FFPROBE_TIMEOUT_SECS)), None).await {
+                Ok(out) => out,
+                Err(error) => return Ok(ToolResult::failure(error.to_string())),
+            };
+        if !out.success() {
+            return Ok(ToolResult::failure(format!(
+                "ffprobe could not read {}: {}",
+                input.display(),
+                out.stderr_tail(DEFAULT_STDERR_TAIL_BYTES)
+            )));
+        }
+
+        let probe: serde_json::Value = match serde_json::from_str(&out.stdout) {
+            Ok(value) => value,
+            Err(e) => return Ok(ToolResult::failure(format!("Invalid ffprobe output: {e}"))),
+        };
+        let mut output = if raw { probe } else { Self::summarize(&probe) };
+        output["path"] = json!(input.display().to_string());
+
+        Ok(ToolResult {
+            success: true,
+            output: serde_json::to_string_pretty(&output).unwrap_or_default(),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+    use std::path::PathBuf;
+
+    fn test_security(workspace: PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::ReadOnly,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    fn sample_probe() -> serde_json::Value {
+        json!({
+            "streams": [
+                {
+                    "index": 0,
+                    "codec_type": "video",
+                    "codec_name": "h264",
+                    "width": 1920,
+                    "height": 1080,
+                    "avg_frame_rate": "30000/1001",
+                    "duration": "12.512500",
+                    "bit_rate": "4500000"
+                },
+                {
+                    "index": 1,
+                    "codec_type": "audio",
+                    "codec_name": "aac",
+                    "sample_rate": "48000",
+                    "channels": 2,
+                    "bit_rate": "128000",
+                    "tags": {"language": "eng"}
+                }
+            ],
+            "format": {
+                "format_name": "mov,mp4,m4a,3gp,3g2,mj2",
+                "duration": "12.512500",
+                "size": "7245312",
+                "bit_rate": "4632412"
+            }
+        })
+    }
+
+    #[test]
+    fn media_probe_tool_name_and_schema() {
+        let tool = MediaProbeTool::new(test_security(std::env::temp_dir()));
+        assert_eq!(tool.name(), "media_probe");
+        let schema = tool.parameters_schema();
+        assert!(schema["properties"]["input"].is_object());
+        assert_eq!(schema["required"], json!(["input"]));
+    }
+
+    #[test]
+    fn ffprobe_args_request_json_format_and_streams() {
+        let args = MediaProbeTool::ffprobe_args(Path::new("/w/a.mp4"));
+        assert_eq!(
+            args,
+            vec![
+                "-v",
+                "error",
+                "-show_format",
+                "-show_streams",
+                "-print_format",
+                "json",
+                "/w/a.mp4"
+            ]
+        );
+    }
+
+    #[test]
+    fn summarize_converts_numeric_strings_and_keeps_stream_details() {
+        let summary = MediaProbeTool::summarize(&sample_probe());
+        assert_eq!(summary["duration"], json!(12.5125));
+        assert_eq!(summary["size"], json!(7_245_312));
+        assert_eq!(summary["streams"][0]["width"], json!(1920));
+        assert_eq!(summary["streams"][0]["frame_rate"], json!("30000/1001"));
+        assert!(summary["streams"][0].get("sample_rate").is_none());
+        assert_eq!(summary["streams"][1]["sample_rate"], json!(48000));
+        assert_eq!(summary["streams"][1]["channels"], json!(2));
+        assert_eq!(summary["streams"][1]["language"], json!("eng"));
+    }
+
+    #[test]
+    fn summarize_tolerates_missing_sections() {
+        let summary = MediaProbeTool::summarize(&json!({}));
+        assert!(summary["duration"].is_null());
+        assert_eq!(summary["streams"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn rejects_missing_input_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaProbeTool::new(test_security(tmp.path().to_path_buf()));
+        let result = tool.execute(json!({"input": "nope.mp4"})).await.unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("File not found"));
+    }
+
+    #[tokio::test]
+    async fn blocks_path_outside_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = MediaProbeTool::new(test_security(tmp.path().to_path_buf()));
+        let result = tool.execute(json!({"input": "/etc/passwd"})).await.unwrap();
+        assert!(!result.success);
+    }
+}
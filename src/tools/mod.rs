@@ -17,9 +17,11 @@
 
 pub mod agents_ipc;
 pub mod apply_patch;
+pub mod binary_resolver;
 pub mod browser;
 pub mod browser_open;
 pub mod cli_discovery;
+pub mod command_runner;
 pub mod composio;
 pub mod content_search;
 pub mod cron_add;
@@ -43,6 +45,11 @@ pub mod hardware_memory_map;
 pub mod hardware_memory_read;
 pub mod http_request;
 pub mod image_info;
+pub mod media_chapter_split;
+pub mod media_clip;
+pub mod media_extract_audio;
+pub mod media_mux;
+pub mod media_probe;
 pub mod memory_forget;
 pub mod memory_recall;
 pub mod memory_store;
@@ -61,10 +68,14 @@ pub mod subagent_registry;
 pub mod subagent_spawn;
 pub mod task_plan;
 pub mod traits;
+pub mod transcript;
+pub mod transcript_merge;
+pub mod transcript_search;
 pub mod url_validation;
 pub mod wasm_module;
 pub mod web_fetch;
 pub mod web_search_tool;
+pub mod workspace_file;
 
 pub use apply_patch::ApplyPatchTool;
 pub use browser::{BrowserTool, ComputerUseConfig};
@@ -92,6 +103,11 @@ pub use hardware_memory_map::HardwareMemoryMapTool;
 pub use hardware_memory_read::HardwareMemoryReadTool;
 pub use http_request::HttpRequestTool;
 pub use image_info::ImageInfoTool;
+pub use media_chapter_split::MediaChapterSplitTool;
+pub use media_clip::MediaClipTool;
+pub use media_extract_audio::MediaExtractAudioTool;
+pub use media_mux::MediaMuxTool;
+pub use media_probe::MediaProbeTool;
 pub use memory_forget::MemoryForgetTool;
 pub use memory_recall::MemoryRecallTool;
 pub use memory_store::MemoryStoreTool;
@@ -113,6 +129,8 @@ pub use task_plan::TaskPlanTool;
 pub use traits::Tool;
 #[allow(unused_imports)]
 pub use traits::{ToolResult, ToolSpec};
+pub use transcript_merge::TranscriptMergeTool;
+pub use transcript_search::TranscriptSearchTool;
 pub use wasm_module::WasmModuleTool;
 pub use web_fetch::WebFetchTool;
 pub use web_search_tool::WebSearchTool;
@@ -155,6 +173,83 @@ impl Tool for ArcDelegatingTool {
     }
 }
 
+/// Wraps a tool so operator-configured default arguments are merged under the
+/// model-supplied arguments before execution (`[agent.tool_defaults]`).
+pub struct DefaultArgsTool {
+    inner: Arc<dyn Tool>,
+    defaults: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DefaultArgsTool {
+    /// Non-object `defaults` are ignored (config validation rejects them).
+    pub fn new(inner: Arc<dyn Tool>, defaults: serde_json::Value) -> Self {
+        let defaults = match defaults {
+            serde_json::Value::Object(map) => map,
+            _ => serde_json::Map::new(),
+        };
+        Self { inner, defaults }
+    }
+
+    /// Shallow merge: top-level keys present in `args` win over defaults.
+    fn merge_args(&self, args: serde_json::Value) -> serde_json::Value {
+        match args {
+            serde_json::Value::Object(provided) => {
+                let mut merged = self.defaults.clone();
+                merged.extend(provided);
+                serde_json::Value::Object(merged)
+            }
+            serde_json::Value::Null => serde_json::Value::Object(self.defaults.clone()),
+            other => other,
+        }
+    }
+}
+
+#[async_trait]
+impl Tool for DefaultArgsTool {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn description(&self) -> &str {
+        self.inner.description()
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        self.inner.parameters_schema()
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        self.inner.execute(self.merge_args(args)).await
+    }
+}
+
+fn apply_tool_defaults(
+    tools: Vec<Arc<dyn Tool>>,
+    tool_defaults: &HashMap<String, serde_json::Value>,
+) -> Vec<Arc<dyn Tool>> {
+    if tool_defaults.is_empty() {
+        return tools;
+    }
+    tools
+        .into_iter()
+        .map(|tool| match tool_defaults.get(tool.name()) {
+            Some(defaults) => {
+                Arc::new(DefaultArgsTool::new(tool, defaults.clone())) as Arc<dyn Tool>
+            }
+            None => tool,
+        })
+        .collect()
+}
+
+/// Snapshot of the registry handed to delegate and sub-agents, with
+/// `[agent.tool_defaults]` applied so delegated calls see the same defaults.
+fn parent_tools_snapshot(
+    tools: &[Arc<dyn Tool>],
+    tool_defaults: &HashMap<String, serde_json::Value>,
+) -> Arc<Vec<Arc<dyn Tool>>> {
+    Arc::new(apply_tool_defaults(tools.to_vec(), tool_defaults))
+}
+
 fn boxed_registry_from_arcs(tools: Vec<Arc<dyn Tool>>) -> Vec<Box<dyn Tool>> {
     tools.into_iter().map(ArcDelegatingTool::boxed).collect()
 }
@@ -292,6 +387,25 @@ pub fn all_tools_with_runtime(
             security.clone(),
             workspace_dir.to_path_buf(),
         )));
+        let media_audit = crate::security::AuditLogger::new(
+            root_config.security.audit.clone(),
+            zeroclaw_dir.clone(),
+        )
+        .ok()
+        .map(Arc::new);
+        tool_arcs.push(Arc::new(
+            MediaClipTool::new(security.clone()).with_audit(media_audit.clone()),
+        ));
+        tool_arcs.push(Arc::new(
+            MediaMuxTool::new(security.clone()).with_audit(media_audit.clone()),
+        ));
+        tool_arcs.push(Arc::new(MediaProbeTool::new(security.clone())));
+        tool_arcs.push(Arc::new(
+            MediaExtractAudioTool::new(security.clone()).with_audit(media_audit.clone()),
+        ));
+        tool_arcs.push(Arc::new(
+            MediaChapterSplitTool::new(security.clone()).with_audit(media_audit),
+        ));
     }
 
     if has_filesystem_access {
@@ -301,6 +415,8 @@ pub fn all_tools_with_runtime(
         tool_arcs.push(Arc::new(ApplyPatchTool::new()));
         tool_arcs.push(Arc::new(GlobSearchTool::new(security.clone())));
         tool_arcs.push(Arc::new(ContentSearchTool::new(security.clone())));
+        tool_arcs.push(Arc::new(TranscriptSearchTool::new(security.clone())));
+        tool_arcs.push(Arc::new(TranscriptMergeTool::new(security.clone())));
     }
     if runtime.as_any().is::<crate::runtime::WasmRuntime>() {
         tool_arcs.push(Arc::new(WasmModuleTool::new(
@@ -426,7 +542,7 @@ pub fn all_tools_with_runtime(
             max_tokens_override: None,
             model_support_vision: root_config.model_support_vision,
         };
-        let parent_tools = Arc::new(tool_arcs.clone());
+        let parent_tools = parent_tools_snapshot(&tool_arcs, &root_config.agent.tool_defaults);
         let mut delegate_tool = DelegateTool::new_with_options(
             delegate_agents.clone(),
             delegate_fallback_credential.clone(),
@@ -520,6 +636,7 @@ pub fn all_tools_with_runtime(
         }
     }
 
+    let tool_arcs = apply_tool_defaults(tool_arcs, &root_config.agent.tool_defaults);
     boxed_registry_from_arcs(tool_arcs)
 }
 
@@ -929,4 +1046,61 @@ mod tests {
         assert!(names.contains(&"delegate"));
         assert!(!names.contains(&"delegate_coordination_status"));
     }
+
+    struct EchoArgsTool;
+
+    #[async_trait]
+    impl Tool for EchoArgsTool {
+        fn name(&self) -> &str {
+            "echo_args"
+        }
+
+        fn description(&self) -> &str {
+            "Echo arguments"
+        }
+
+        fn parameters_schema(&self) -> serde_json::Value {
+            serde_json::json!({ "type": "object" })
+        }
+
+        async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+            Ok(ToolResult {
+                success: true,
+                output: args.to_string(),
+                error: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn default_args_tool_merges_defaults_and_args_win() {
+        let tool = DefaultArgsTool::new(
+            Arc::new(EchoArgsTool),
+            serde_json::json!({ "quality": "1080p", "mode": "video" }),
+        );
+        let result = tool
+            .execute(serde_json::json!({ "mode": "audio", "url": "x" }))
+            .await
+            .unwrap();
+        let echoed: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(
+            echoed,
+            serde_json::json!({ "quality": "1080p", "mode": "audio", "url": "x" })
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_tool_defaults_wraps_configured_tools_by_name() {
+        let tools: Vec<Arc<dyn Tool>> = vec![Arc::new(EchoArgsTool)];
+        let defaults = HashMap::from([(
+            "echo_args".to_string(),
+            serde_json::json!({ "mode": "video" }),
+        )]);
+        let wrapped = apply_tool_defaults(tools, &defaults);
+        assert_eq!(wrapped.len(), 1);
+        assert_eq!(wrapped[0].name(), "echo_args");
+
+        let result = wrapped[0].execute(serde_json::json!({})).await.unwrap();
+        assert_eq!(result.output, r#"{"mode":"video"}"#);
+    }
 }
@@ -0,0 +1,490 @@
+//! Shared helpers for timed transcripts consumed by transcript tools.
+//!
+//! Transcripts are Whisper-style `verbose_json` (an object with a `segments`
+//! array) or a bare array of `{start, end, text}` objects.
+
+use super::workspace_file::resolve_workspace_file;
+use crate::security::SecurityPolicy;
+use std::fmt::Write as _;
+
+/// Maximum transcript file size we will load (10 MB).
+pub const MAX_TRANSCRIPT_BYTES: u64 = 10 * 1024 * 1024;
+
+/// A single timed transcript segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptSegment {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    /// Recognition confidence in `0.0..=1.0`, when the source provides one.
+    pub confidence: Option<f64>,
+    /// Whisper's probability that the segment is silence, when provided.
+    pub no_speech_prob: Option<f64>,
+    /// Word-level timings, when the backend produced them.
+    pub words: Vec<TranscriptWord>,
+}
+
+/// Default `max_no_speech_prob`: Whisper's own `no_speech_threshold`.
+pub const DEFAULT_MAX_NO_SPEECH_PROB: f64 = 0.6;
+
+impl TranscriptSegment {
+    /// Whether the segment is likely text hallucinated over silence.
+    ///
+    /// Either signal is enough: `no_speech_prob` above `max_no_speech_prob`,
+    /// or `confidence` below `min_confidence`. A missing score never counts
+    /// against the segment.
+    pub fn is_probable_silence(&self, min_confidence: f64, max_no_speech_prob: f64) -> bool {
+        self.no_speech_prob
+            .is_some_and(|no_speech_prob| no_speech_prob > max_no_speech_prob)
+            || self
+                .confidence
+                .is_some_and(|confidence| confidence < min_confidence)
+    }
+}
+
+/// A single timed word inside a [`TranscriptSegment`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptWord {
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+}
+
+/// Extract segments from `verbose_json` or a bare segment array.
+///
+/// Confidence is read from a `confidence` field, or derived from Whisper's
+/// `avg_logprob` as `exp(avg_logprob)`; `no_speech_prob` is kept as-is.
+/// Word timings are read from a `words` array of `{start, end, word}`
+/// entries; malformed words are skipped.
+pub fn parse_segments(value: &serde_json::Value) -> Result<Vec<TranscriptSegment>, String> {
+    let items =
+        value.get("segments").unwrap_or(value).as_array().ok_or(
+            "Transcript must be an array of segments or an object with a 'segments' array",
+        )?;
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let start = item.get("start").and_then(serde_json::Value::as_f64);
+            let end = item.get("end").and_then(serde_json::Value::as_f64);
+            let text = item.get("text").and_then(serde_json::Value::as_str);
+            let confidence = item
+                .get("confidence")
+                .and_then(serde_json::Value::as_f64)
+                .or_else(|| {
+                    item.get("avg_logprob")
+                        .and_then(serde_json::Value::as_f64)
+                        .map(f64::exp)
+                });
+            let no_speech_prob = item
+                .get("no_speech_prob")
+                .and_then(serde_json::Value::as_f64);
+            let words = item
+                .get("words")
+                .and_then(serde_json::Value::as_array)
+                .map(|words| words.iter().filter_map(parse_word).collect())
+                .unwrap_or_default();
+            match (start, end, text) {
+                (Some(start), Some(end), Some(text)) => Ok(TranscriptSegment {
+                    start,
+                    end,
+                    text: text.trim().to_string(),
+                    confidence,
+                    no_speech_prob,
+                    words,
+                }),
+                _ => Err(format!(
+                    "Segment {i} is missing numeric 'start'/'end' or string 'text'"
+                )),
+            }
+        })
+        .collect()
+}
+
+fn parse_word(item: &serde_json::Value) -> Option<TranscriptWord> {
+    let text = item
+        .get("word")
+        .or_else(|| item.get("text"))
+        .and_then(serde_json::Value::as_str)?
+        .trim();
+    if text.is_empty() {
+        return None;
+    }
+    Some(TranscriptWord {
+        start: item.get("start")?.as_f64()?,
+        end: item.get("end")?.as_f64()?,
+        text: text.to_string(),
+    })
+}
+
+/// Format seconds as `HH:MM:SS<sep>mmm` (`.` for display/VTT, `,` for SRT).
+pub fn format_timestamp(seconds: f64, ms_separator: char) -> String {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let total_ms = (seconds.max(0.0) * 1000.0).round() as u64;
+    let (hours, rem) = (total_ms / 3_600_000, total_ms % 3_600_000);
+    let (minutes, rem) = (rem / 60_000, rem % 60_000);
+    let (secs, ms) = (rem / 1000, rem % 1000);
+    format!("{hours:02}:{minutes:02}:{secs:02}{ms_separator}{ms:03}")
+}
+
+/// Render segments as SubRip (`.srt`).
+pub fn to_srt(segments: &[TranscriptSegment]) -> String {
+    let mut out = String::new();
+    for (i, segment) in segments.iter().enumerate() {
+        let _ = write!(
+            out,
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            format_timestamp(segment.start, ','),
+            format_timestamp(segment.end, ','),
+            segment.text
+        );
+    }
+    out
+}
+
+/// Render segments as SubRip, wrapping cues to at most `max_chars_per_line`
+/// characters per line and `max_lines_per_cue` lines per cue.
+///
+/// Long segments are split on word boundaries into several cues. Cue timing
+/// comes from word timestamps when present; otherwise the segment's span is
+/// divided in proportion to word length. A single word longer than the limit
+/// gets a line of its own.
+pub fn to_srt_wrapped(
+    segments: &[TranscriptSegment],
+    max_chars_per_line: usize,
+    max_lines_per_cue: usize,
+) -> String {
+    let max_chars_per_line = max_chars_per_line.max(1);
+    let max_lines_per_cue = max_lines_per_cue.max(1);
+    let mut out = String::new();
+    let mut cue_number = 0;
+
+    for segment in segments {
+        let words = timed_words(segment);
+        let mut lines: Vec<Vec<&TranscriptWord>> = Vec::new();
+        for word in &words {
+            match lines.last_mut() {
+                Some(line)
+                    if line_len(line) + 1 + word.text.chars().count() <= max_chars_per_line =>
+                {
+                    line.push(word);
+                }
+                _ => lines.push(vec![word]),
+            }
+        }
+
+        for cue in lines.chunks(max_lines_per_cue) {
+            let (Some(first), Some(last)) = (
+                cue.first().and_then(|line| line.first()),
+                cue.last().and_then(|line| line.last()),
+            ) else {
+                continue;
+            };
+            cue_number += 1;
+            let text = cue
+                .iter()
+                .map(|line| {
+                    line.iter()
+                        .map(|word| word.text.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            let _ = write!(
+                out,
+                "{cue_number}\n{} --> {}\n{text}\n\n",
+                format_timestamp(first.start, ','),
+                format_timestamp(last.end, ',')
+            );
+        }
+    }
+    out
+}
+
+fn line_len(line: &[&TranscriptWord]) -> usize {
+    line.iter()
+        .map(|word| word.text.chars().count())
+        .sum::<usize>()
+        + line.len().saturating_sub(1)
+}
+
+/// Word timings for a segment, estimated from text length when absent.
+fn timed_words(segment: &TranscriptSegment) -> Vec<TranscriptWord> {
+    if !segment.words.is_empty() {
+        return segment.words.clone();
+    }
+
+    let tokens: Vec<&str> = segment.text.split_whitespace().collect();
+    let total_chars: usize = tokens.iter().map(|t| t.chars().count()).sum();
+    let span = (segment.end - segment.start).max(0.0);
+    let mut consumed = 0;
+    tokens
+        .into_iter()
+        .map(|token| {
+            let at = |chars: usize| segment.start + span * chars as f64 / total_chars.max(1) as f64;
+            let start = at(consumed);
+            consumed += token.chars().count();
+            TranscriptWord {
+                start,
+                end: at(consumed),
+                text: token.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Render segments as WebVTT (`.vtt`).
+pub fn to_vtt(segments: &[TranscriptSegment]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for segment in segments {
+        let _ = write!(
+            out,
+            "{} --> {}\n{}\n\n",
+            format_timestamp(segment.start, '.'),
+            format_timestamp(segment.end, '.'),
+            segment.text
+        );
+    }
+    out
+}
+
+/// Render segments as plain text, one segment per line.
+pub fn to_text(segments: &[TranscriptSegment]) -> String {
+    segments
+        .iter()
+        .map(|segment| segment.text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render segments as a `verbose_json`-compatible object.
+pub fn to_json(segments: &[TranscriptSegment]) -> serde_json::Value {
+    let items: Vec<serde_json::Value> = segments
+        .iter()
+        .map(|segment| {
+            let mut item = serde_json::json!({
+                "start": segment.start,
+                "end": segment.end,
+                "text": segment.text,
+            });
+            if let Some(confidence) = segment.confidence {
+                item["confidence"] = serde_json::json!(confidence);
+            }
+            if let Some(no_speech_prob) = segment.no_speech_prob {
+                item["no_speech_prob"] = serde_json::json!(no_speech_prob);
+            }
+            if !segment.words.is_empty() {
+                item["words"] = segment
+                    .words
+                    .iter()
+                    .map(|word| {
+                        serde_json::json!({
+                            "start": word.start,
+                            "end": word.end,
+                            "word": word.text,
+                        })
+                    })
+                    .collect();
+            }
+            item
+        })
+        .collect();
+    serde_json::json!({
+        "text": to_text(segments),
+        "segments": items,
+    })
+}
+
+/// Resolve a transcript path inside the workspace and parse it as JSON.
+pub async fn read_transcript_file(
+    security: &SecurityPolicy,
+    path_str: &str,
+) -> Result<serde_json::Value, String> {
+    let resolved = resolve_workspace_file(security, path_str)?;
+    let meta = tokio::fs::metadata(&resolved)
+        .await
+        .map_err(|e| format!("Failed to read file metadata: {e}"))?;
+    if meta.len() > MAX_TRANSCRIPT_BYTES {
+        return Err(format!(
+            "Transcript too large: {} bytes (limit: {MAX_TRANSCRIPT_BYTES} bytes)",
+            meta.len()
+        ));
+    }
+    let raw = tokio::fs::read_to_string(&resolved)
+        .await
+        .map_err(|e| format!("Failed to read transcript: {e}"))?;
+    serde_json::from_str(&raw).map_err(|e| format!("Invalid transcript JSON: {e}"))
+}
+
+/// Load a transcript from either a `path` or an inline `transcript` field of `args`.
+///
+/// An inline transcript may be a JSON value or a string containing JSON.
+pub async fn load_transcript_arg(
+    security: &SecurityPolicy,
+    args: &serde_json::Value,
+) -> Result<serde_json::Value, String> {
+    match (args.get("path"), args.get("transcript")) {
+        (Some(_), Some(_)) => Err("Provide either 'path' or 'transcript', not both".into()),
+        (None, None) => Err("Missing 'path' or 'transcript' parameter".into()),
+        (None, Some(serde_json::Value::String(raw))) => {
+            serde_json::from_str(raw).map_err(|e| format!("Invalid transcript JSON: {e}"))
+        }
+        (None, Some(inline)) => Ok(inline.clone()),
+        (Some(path), None) => {
+            let path_str = path.as_str().ok_or("'path' must be a string")?;
+            read_transcript_file(security, path_str).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn segments() -> Vec<TranscriptSegment> {
+        vec![
+            TranscriptSegment {
+                start: 0.0,
+                end: 1.5,
+                text: "Hello".into(),
+                confidence: None,
+                no_speech_prob: None,
+                words: Vec::new(),
+            },
+            TranscriptSegment {
+                start: 61.25,
+                end: 62.0,
+                text: "World".into(),
+                confidence: Some(0.9),
+                no_speech_prob: None,
+                words: vec![TranscriptWord {
+                    start: 61.25,
+                    end: 62.0,
+                    text: "World".into(),
+                }],
+            },
+        ]
+    }
+
+    #[test]
+    fn parse_segments_reads_verbose_json_and_derives_confidence() {
+        let value = json!({"segments": [
+            {"start": 0.0, "end": 1.0, "text": " hi ", "avg_logprob": 0.0},
+            {"start": 1.0, "end": 2.0, "text": "there", "confidence": 0.5}
+        ]});
+        let parsed = parse_segments(&value).unwrap();
+        assert_eq!(parsed[0].text, "hi");
+        assert_eq!(parsed[0].confidence, Some(1.0));
+        assert_eq!(parsed[1].confidence, Some(0.5));
+    }
+
+    #[test]
+    fn parse_segments_accepts_bare_array_and_rejects_malformed() {
+        let bare = json!([{"start": 1, "end": 2, "text": "hi"}]);
+        let parsed = parse_segments(&bare).unwrap();
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].start, 1.0);
+
+        let value = json!([{"start": 1, "text": "no end"}]);
+        assert!(parse_segments(&value).is_err());
+        assert!(parse_segments(&json!("nope")).is_err());
+    }
+
+    #[test]
+    fn format_timestamp_uses_requested_separator() {
+        assert_eq!(format_timestamp(3725.5, '.'), "01:02:05.500");
+        assert_eq!(format_timestamp(0.0, ','), "00:00:00,000");
+    }
+
+    #[test]
+    fn srt_and_vtt_render_cues() {
+        let srt = to_srt(&segments());
+        assert!(srt.starts_with("1\n00:00:00,000 --> 00:00:01,500\nHello\n\n2\n"));
+        let vtt = to_vtt(&segments());
+        assert!(vtt.starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nHello"));
+    }
+
+    #[test]
+    fn json_round_trips_through_parse_segments() {
+        let rendered = to_json(&segments());
+        assert_eq!(rendered["text"], "Hello\nWorld");
+        assert_eq!(parse_segments(&rendered).unwrap(), segments());
+    }
+
+    #[test]
+    fn parse_segments_reads_word_timings_and_skips_malformed_words() {
+        let value = json!([{
+            "start": 0.0, "end": 1.0, "text": "a b",
+            "words": [
+                {"start": 0.0, "end": 0.4, "word": " a"},
+                {"start": 0.4, "word": "broken"},
+                {"start": 0.5, "end": 1.0, "word": "b"}
+            ]
+        }]);
+        let parsed = parse_segments(&value).unwrap();
+        let words: Vec<&str> = parsed[0].words.iter().map(|w| w.text.as_str()).collect();
+        assert_eq!(words, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn wrapped_srt_splits_cues_on_word_timestamps() {
+        let words = ["one", "two", "three", "four", "five"];
+        let segment = TranscriptSegment {
+            start: 0.0,
+            end: 5.0,
+            text: words.join(" "),
+            confidence: None,
+            no_speech_prob: None,
+            words: words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| TranscriptWord {
+                    start: i as f64,
+                    end: i as f64 + 1.0,
+                    text: (*w).into(),
+                })
+                .collect(),
+        };
+        let srt = to_srt_wrapped(&[segment], 9, 2);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:03,000\none two\nthree\n\n\
+             2\n00:00:03,000 --> 00:00:05,000\nfour five\n\n"
+        );
+    }
+
+    #[test]
+    fn wrapped_srt_estimates_timing_without_words_and_keeps_long_words() {
+        let segment = TranscriptSegment {
+            start: 10.0,
+            end: 14.0,
+            text: "ab cd supercalifragilistic".into(),
+            confidence: None,
+            no_speech_prob: None,
+            words: Vec::new(),
+        };
+        let srt = to_srt_wrapped(&[segment], 5, 1);
+        assert!(srt.starts_with("1\n00:00:10,000 --> 00:00:10,667\nab cd\n\n"));
+        assert!(srt.ends_with("2\n00:00:10,667 --> 00:00:14,000\nsupercalifragilistic\n\n"));
+    }
+
+    #[test]
+    fn probable_silence_on_either_weak_signal() {
+        let mut segment = segments().remove(0);
+        assert!(!segment.is_probable_silence(0.4, 0.6));
+        segment.confidence = Some(0.9);
+        segment.no_speech_prob = Some(0.5);
+        assert!(!segment.is_probable_silence(0.4, 0.6));
+        segment.no_speech_prob = Some(0.65);
+        assert!(segment.is_probable_silence(0.4, 0.6));
+        assert!(!segment.is_probable_silence(0.4, 0.7));
+        segment.no_speech_prob = None;
+        segment.confidence = Some(0.2);
+        assert!(segment.is_probable_silence(0.4, 0.6));
+        assert!(!segment.is_probable_silence(0.1, 0.6));
+    }
+}
@@ -0,0 +1,561 @@
+use super::traits::{Tool, ToolResult};
+use super::transcript::{self, TranscriptSegment};
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+const MAX_INPUTS: usize = 32;
+const DEFAULT_SRT_MAX_LINES_PER_CUE: usize = 2;
+/// Default `min_confidence` for subtitle output: `exp(-1)`, i.e. Whisper's
+/// `avg_logprob` threshold of -1.0 expressed as a confidence.
+const DEFAULT_MIN_CONFIDENCE: f64 = 1.0 / std::f64::consts::E;
+
+/// Merge several timed transcripts into a single time-sorted segment list.
+///
+/// Each input may carry an `offset` (seconds) that is added to its timestamps,
+/// so sequential recordings can be laid end to end. Where segments from
+/// different inputs overlap, the higher-confidence one is kept.
+pub struct TranscriptMergeTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl TranscriptMergeTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+
+    /// Sort segments by time and resolve overlaps in favour of higher confidence.
+    ///
+    /// Segments without a confidence lose to any segment that has one; on a tie
+    /// the earlier segment is kept.
+    fn merge_segments(mut segments: Vec<TranscriptSegment>) -> Vec<TranscriptSegment> {
+        segments.sort_by(|a, b| a.start.total_cmp(&b.start).then(a.end.total_cmp(&b.end)));
+
+        let mut merged: Vec<TranscriptSegment> = Vec::with_capacity(segments.len());
+        for segment in segments {
+            match merged.last_mut() {
+                Some(previous) if segment.start < previous.end => {
+                    let rank = |s: &TranscriptSegment| s.confidence.unwrap_or(f64::NEG_INFINITY);
+                    if rank(&segment) > rank(previous) {
+                        *previous = segment;
+                    }
+                }
+                _ => merged.push(segment),
+            }
+        }
+        merged
+    }
+
+    /// Drop segments that are probably text hallucinated over silence.
+    ///
+    /// See [`TranscriptSegment::is_probable_silence`]. Returns the kept
+    /// segments and how many were dropped.
+    fn filter_low_confidence(
+        segments: Vec<TranscriptSegment>,
+        min_confidence: f64,
+        max_no_speech_prob: f64,
+    ) -> (Vec<TranscriptSegment>, usize) {
+        let total = segments.len();
+        let kept: Vec<TranscriptSegment> = segments
+            .into_iter()
+            .filter(|segment| !segment.is_probable_silence(min_confidence, max_no_speech_prob))
+            .collect();
+        let dropped = total - kept.len();
+        (kept, dropped)
+    }
+
+    /// Render in `format`; `srt_wrap` is `(max_chars_per_line, max_lines_per_cue)`.
+    ///
+    /// `filtered` is how many segments the silence filter dropped, or `None`
+    /// when it did not run; JSON reports it as `filtered_segments`.
+    fn render(
+        segments: &[TranscriptSegment],
+        format: &str,
+        srt_wrap: Option<(usize, usize)>,
+        filtered: Option<usize>,
+    ) -> Result<String, String> {
+        match format {
+            "json" => {
+                let mut value = transcript::to_json(segments);
+                if let Some(filtered) = filtered {
+                    value["filtered_segments"] = json!(filtered);
+                }
+                Ok(serde_json::to_string_pretty(&value).unwrap_or_default())
+            }
+            "srt" => Ok(match srt_wrap {
+                Some((max_chars, max_lines)) => {
+                    transcript::to_srt_wrapped(segments, max_chars, max_lines)
+                }
+                None => transcript::to_srt(segments),
+            }),
+            "vtt" => Ok(transcript::to_vtt(segments)),
+            "text" => Ok(transcript::to_text(segments)),
+            other => Err(format!(
+                "Unsupported format '{other}'. Use one of: json, srt, vtt, text"
+            )),
+        }
+    }
+
+    async fn load_input(
+        &self,
+        index: usize,
+        input: &serde_json::Value,
+    ) -> Result<Vec<TranscriptSegment>, String> {
+        let offset = match input.get("offset") {
+            None => 0.0,
+            Some(value) => value.as_f64().filter(|v| v.is_finite()).ok_or_else(|| {
+                format!("transcripts[{index}].offset must be a number of seconds")
+            })?,
+        };
+        let value = transcript::load_transcript_arg(&self.security, input)
+            .await
+            .map_err(|e| format!("transcripts[{index}]: {e}"))?;
+        let mut segments =
+            transcript::parse_segments(&value).map_err(|e| format!("transcripts[{index}]: {e}"))?;
+        for segment in &mut segments {
+            segment.start += offset;
+            segment.end += offset;
+            for word in &mut segment.words {
+                word.start += offset;
+                word.end += offset;
+            }
+        }
+        Ok(segments)
+    }
+}
+
+#[async_trait]
+impl Tool for TranscriptMergeTool {
+    fn name(&self) -> &str {
+        "transcript_merge"
+    }
+
+    fn description(&self) -> &str {
+        "Merge multiple timed transcripts (inline JSON or workspace files) into one time-sorted \
+         timeline, with optional per-input time offsets. Overlapping segments keep the \
+         higher-confidence one. Segments likely hallucinated over silence are dropped from srt/vtt \
+         output by default. Output as json, srt, vtt, or plain text."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "transcripts": {
+                    "type": "array",
+                    "description": "Transcripts to merge (max 32)",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "path": {
+                                "type": "string",
+                                "description": "Path to a transcript JSON file (absolute or relative to workspace)"
+                            },
+                            "transcript": {
+                                "description": "Inline transcript JSON (object or string). Provide either 'path' or 'transcript'."
+                            },
+                            "offset": {
+                                "type": "number",
+                                "description": "Seconds added to every timestamp in this transcript. Default: 0"
+                            }
+                        }
+                    }
+                },
+                "format": {
+                    "type": "string",
+                    "enum": ["json", "srt", "vtt", "text"],
+                    "description": "Output format. Default: json",
+                    "default": "json"
+                },
+                "srt_max_line_length": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "For srt output: wrap cues on word boundaries to this many characters per line, splitting long segments into several cues timed from word timestamps. Default: no wrapping"
+                },
+                "srt_max_lines_per_cue": {
+                    "type": "integer",
+                    "minimum": 1,
+                    "description": "For srt output with srt_max_line_length: maximum lines per cue. Default: 2",
+                    "default": 2
+                },
+                "min_confidence": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 1,
+                    "description": "Drop segments likely hallucinated over silence: confidence (from avg_logprob/confidence) below this, or no_speech_prob above max_no_speech_prob. Default: 0.37 (avg_logprob -1). srt/vtt are always filtered; json and text only when this or max_no_speech_prob is set. JSON output reports the dropped count as filtered_segments"
+                },
+                "max_no_speech_prob": {
+                    "type": "number",
+                    "minimum": 0,
+                    "maximum": 1,
+                    "description": "Silence filter: drop segments whose no_speech_prob is above this. Default: 0.6",
+                    "default": transcript::DEFAULT_MAX_NO_SPEECH_PROB
+                }
+            },
+            "required": ["transcripts"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let inputs = args
+            .get("transcripts")
+            .and_then(serde_json::Value::as_array)
+            .ok_or_else(|| anyhow::anyhow!("Missing 'transcripts' parameter"))?;
+        if inputs.is_empty() {
+            return Ok(ToolResult::failure(
+                "'transcripts' must contain at least one transcript",
+            ));
+        }
+        if inputs.len() > MAX_INPUTS {
+            return Ok(ToolResult::failure(format!(
+                "Too many transcripts: {} (limit: {MAX_INPUTS})",
+                inputs.len()
+            )));
+        }
+        let format = args
+            .get("format")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("json");
+        #[allow(clippy::cast_possible_truncation)]
+        let srt_wrap = args
+            .get("srt_max_line_length")
+            .and_then(serde_json::Value::as_u64)
+            .map(|max_chars| {
+                let max_lines = args
+                    .get("srt_max_lines_per_cue")
+                    .and_then(serde_json::Value::as_u64)
+                    .map_or(DEFAULT_SRT_MAX_LINES_PER_CUE, |v| v as usize);
+                (max_chars as usize, max_lines)
+            });
+        let mut thresholds = [None, None];
+        for (key, threshold) in ["min_confidence", "max_no_speech_prob"]
+            .into_iter()
+            .zip(&mut thresholds)
+        {
+            if let Some(value) = args.get(key) {
+                match value.as_f64() {
+                    Some(v) if (0.0..=1.0).contains(&v) => *threshold = Some(v),
+                    _ => {
+                        return Ok(ToolResult::failure(format!(
+                            "'{key}' must be a number between 0 and 1"
+                        )));
+                    }
+                }
+            }
+        }
+        let [min_confidence, max_no_speech_prob] = thresholds;
+        // Subtitles are what viewers see, so they are filtered by default;
+        // json and text keep every segment unless asked.
+        let silence_filter = (matches!(format, "srt" | "vtt")
+            || min_confidence.is_some()
+            || max_no_speech_prob.is_some())
+        .then(|| {
+            (
+                min_confidence.unwrap_or(DEFAULT_MIN_CONFIDENCE),
+                max_no_speech_prob.unwrap_or(transcript::DEFAULT_MAX_NO_SPEECH_PROB),
+            )
+        });
+
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let mut segments = Vec::new();
+        for (index, input) in inputs.iter().enumerate() {
+            match self.load_input(index, input).await {
+                Ok(loaded) => segments.extend(loaded),
+                Err(error) => return Ok(ToolResult::failure(error)),
+            }
+        }
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let (segments, filtered) = match silence_filter {
+            Some((min_confidence, max_no_speech_prob)) => {
+                let (kept, filtered) =
+                    Self::filter_low_confidence(segments, min_confidence, max_no_speech_prob);
+                if filtered > 0 {
+                    tracing::debug!(
+                        filtered,
+                        min_confidence,
+                        max_no_speech_prob,
+                        "dropped transcript segments likely hallucinated over silence"
+                    );
+                }
+                (kept, Some(filtered))
+            }
+            None => (segments, None),
+        };
+        let merged = Self::merge_segments(segments);
+        match Self::render(&merged, format, srt_wrap, filtered) {
+            Ok(output) => Ok(ToolResult {
+                success: true,
+                output,
+                error: None,
+            }),
+            Err(error) => Ok(ToolResult::failure(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+    use std::path::PathBuf;
+
+    fn test_security(workspace: PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    fn segment(start: f64, end: f64, text: &str, confidence: Option<f64>) -> TranscriptSegment {
+        TranscriptSegment {
+            start,
+            end,
+            text: text.into(),
+            confidence,
+            no_speech_prob: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn merge_sorts_and_keeps_higher_confidence_on_overlap() {
+        let merged = TranscriptMergeTool::merge_segments(vec![
+            segment(5.0, 7.0, "later", None),
+            segment(0.0, 2.0, "mic a", Some(0.4)),
+            segment(0.5, 2.5, "mic b", Some(0.8)),
+        ]);
+        let texts: Vec<&str> = merged.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["mic b", "later"]);
+    }
+
+    #[test]
+    fn merge_prefers_scored_segment_and_keeps_earlier_on_tie() {
+        let merged = TranscriptMergeTool::merge_segments(vec![
+            segment(0.0, 2.0, "unscored", None),
+            segment(1.0, 3.0, "scored", Some(0.1)),
+            segment(4.0, 6.0, "first", Some(0.5)),
+            segment(4.5, 6.0, "second", Some(0.5)),
+        ]);
+        let texts: Vec<&str> = merged.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["scored", "first"]);
+    }
+
+    #[test]
+    fn adjacent_segments_are_not_overlaps() {
+        let merged = TranscriptMergeTool::merge_segments(vec![
+            segment(0.0, 2.0, "a", Some(0.9)),
+            segment(2.0, 4.0, "b", Some(0.1)),
+        ]);
+        assert_eq!(merged.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn applies_offsets_and_renders_srt() {
+        let tool = TranscriptMergeTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({
+                "format": "srt",
+                "transcripts": [
+                    {"transcript": [{"start": 0.0, "end": 1.0, "text": "part one"}]},
+                    {"transcript": [{"start": 0.0, "end": 1.0, "text": "part two"}], "offset": 60}
+                ]
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert!(result
+            .output
+            .contains("2\n00:01:00,000 --> 00:01:01,000\npart two"));
+    }
+
+    #[tokio::test]
+    async fn reads_inputs_from_workspace_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        tokio::fs::write(
+            tmp.path().join("a.json"),
+            json!({"segments": [{"start": 3.0, "end": 4.0, "text": "world"}]}).to_string(),
+        )
+        .await
+        .unwrap();
+        let tool = TranscriptMergeTool::new(test_security(tmp.path().to_path_buf()));
+        let result = tool
+            .execute(json!({
+                "format": "text",
+                "transcripts": [
+                    {"path": "a.json"},
+                    {"transcript": "[{\"start\": 0, \"end\": 1, \"text\": \"hello\"}]"}
+                ]
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(result.output, "hello\nworld");
+    }
+
+    #[tokio::test]
+    async fn reports_which_input_failed() {
+        let tool = TranscriptMergeTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({
+                "transcripts": [
+                    {"transcript": []},
+                    {"transcript": [{"start": 0}]}
+                ]
+            }))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().starts_with("transcripts[1]:"));
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_format_and_empty_input() {
+        let tool = TranscriptMergeTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({"format": "docx", "transcripts": [{"transcript": []}]}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Unsupported format"));
+
+        let result = tool.execute(json!({"transcripts": []})).await.unwrap();
+        assert!(!result.success);
+    }
+
+    #[tokio::test]
+    async fn wraps_srt_cues_using_shifted_word_timestamps() {
+        let tool = TranscriptMergeTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({
+                "format": "srt",
+                "srt_max_line_length": 10,
+                "srt_max_lines_per_cue": 1,
+                "transcripts": [{
+                    "offset": 30,
+                    "transcript": [{
+                        "start": 0.0, "end": 2.0, "text": "hello there world",
+                        "words": [
+                            {"start": 0.0, "end": 0.5, "word": "hello"},
+                            {"start": 0.5, "end": 1.0, "word": "there"},
+                            {"start": 1.2, "end": 2.0, "word": "world"}
+                        ]
+                    }]
+                }]
+            }))
+            .await
+            .unwrap();
+        assert!(result.success, "{:?}", result.error);
+        assert_eq!(
+            result.output,
+            "1\n00:00:30,000 --> 00:00:30,500\nhello\n\n\
+             2\n00:00:30,500 --> 00:00:31,000\nthere\n\n\
+             3\n00:00:31,200 --> 00:00:32,000\nworld\n\n"
+        );
+    }
+
+    fn scored(text: &str, confidence: f64, no_speech_prob: f64) -> TranscriptSegment {
+        let mut segment = segment(0.0, 1.0, text, Some(confidence));
+        segment.no_speech_prob = Some(no_speech_prob);
+        segment
+    }
+
+    #[test]
+    fn filter_drops_segments_with_either_weak_signal() {
+        let (kept, dropped) = TranscriptMergeTool::filter_low_confidence(
+            vec![
+                segment(0.0, 1.0, "unscored", None),
+                segment(1.0, 2.0, "low confidence", Some(0.2)),
+                scored("likely silence", 0.9, 0.65),
+                scored("real speech", 0.9, 0.1),
+                scored("Thanks for watching!", 0.2, 0.9),
+            ],
+            DEFAULT_MIN_CONFIDENCE,
+            transcript::DEFAULT_MAX_NO_SPEECH_PROB,
+        );
+        let texts: Vec<&str> = kept.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["unscored", "real speech"]);
+        assert_eq!(dropped, 3);
+    }
+
+    #[tokio::test]
+    async fn filters_subtitles_by_default_and_reports_count_in_json() {
+        let tool = TranscriptMergeTool::new(test_security(std::env::temp_dir()));
+        let transcript = json!({"segments": [
+            {"start": 0.0, "end": 2.0, "text": "real speech", "avg_logprob": -0.2, "no_speech_prob": 0.01},
+            {"start": 2.0, "end": 4.0, "text": "Thanks for watching!", "avg_logprob": -1.4, "no_speech_prob": 0.9}
+        ]});
+
+        let result = tool
+            .execute(json!({"format": "srt", "transcripts": [{"transcript": transcript.clone()}]}))
+            .await
+            .unwrap();
+        assert!(result.output.contains("real speech"));
+        assert!(!result.output.contains("Thanks"));
+
+        // json is unfiltered unless asked, and then reports no count.
+        let result = tool
+            .execute(json!({"transcripts": [{"transcript": transcript.clone()}]}))
+            .await
+            .unwrap();
+        let out: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(out["text"], "real speech\nThanks for watching!");
+        assert!(out.get("filtered_segments").is_none());
+
+        let result = tool
+            .execute(json!({
+                "max_no_speech_prob": 0.5,
+                "transcripts": [{"transcript": transcript.clone()}]
+            }))
+            .await
+            .unwrap();
+        let out: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(out["text"], "real speech");
+        assert_eq!(out["filtered_segments"], 1);
+
+        // Loose enough thresholds keep everything, and say so.
+        let result = tool
+            .execute(json!({
+                "min_confidence": 0.1,
+                "max_no_speech_prob": 0.95,
+                "transcripts": [{"transcript": transcript.clone()}]
+            }))
+            .await
+            .unwrap();
+        let out: serde_json::Value = serde_json::from_str(&result.output).unwrap();
+        assert_eq!(out["filtered_segments"], 0);
+
+        let result = tool
+            .execute(json!({
+                "format": "text",
+                "min_confidence": DEFAULT_MIN_CONFIDENCE,
+                "transcripts": [{"transcript": transcript}]
+            }))
+            .await
+            .unwrap();
+        assert_eq!(result.output, "real speech");
+
+        let result = tool
+            .execute(json!({"min_confidence": 2, "transcripts": [{"transcript": []}]}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        let result = tool
+            .execute(json!({"max_no_speech_prob": -1, "transcripts": [{"transcript": []}]}))
+            .await
+            .unwrap();
+        assert!(result.error.unwrap().contains("'max_no_speech_prob'"));
+    }
+}
@@ -0,0 +1,287 @@
+use super::traits::{Tool, ToolResult};
+use super::transcript::{self, TranscriptSegment};
+use crate::security::SecurityPolicy;
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+
+const DEFAULT_MAX_RESULTS: usize = 50;
+const MAX_RESULTS: usize = 1000;
+
+/// Search a timed transcript for a phrase and return matching segments with timestamps.
+///
+/// Accepts Whisper-style `verbose_json` (an object with a `segments` array) or a
+/// bare array of `{start, end, text}` objects, either inline or from a workspace file.
+pub struct TranscriptSearchTool {
+    security: Arc<SecurityPolicy>,
+}
+
+impl TranscriptSearchTool {
+    pub fn new(security: Arc<SecurityPolicy>) -> Self {
+        Self { security }
+    }
+
+    /// Build a matcher; plain queries are escaped so they match literally.
+    fn build_matcher(
+        query: &str,
+        use_regex: bool,
+        case_sensitive: bool,
+    ) -> Result<regex::Regex, String> {
+        let pattern = if use_regex {
+            query.to_string()
+        } else {
+            regex::escape(query)
+        };
+        regex::RegexBuilder::new(&pattern)
+            .case_insensitive(!case_sensitive)
+            .size_limit(1 << 20)
+            .build()
+            .map_err(|e| format!("Invalid regex: {e}"))
+    }
+}
+
+#[async_trait]
+impl Tool for TranscriptSearchTool {
+    fn name(&self) -> &str {
+        "transcript_search"
+    }
+
+    fn description(&self) -> &str {
+        "Search a timed transcript (Whisper verbose_json or an array of {start, end, text} segments) \
+         for a phrase or regex and return matching segments with timestamps."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "Phrase to search for (literal unless 'regex' is true)"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Path to a transcript JSON file (absolute or relative to workspace)"
+                },
+                "transcript": {
+                    "description": "Inline transcript JSON (object or string). Provide either 'path' or 'transcript'."
+                },
+                "regex": {
+                    "type": "boolean",
+                    "description": "Treat 'query' as a regular expression. Default: false",
+                    "default": false
+                },
+                "case_sensitive": {
+                    "type": "boolean",
+                    "description": "Case-sensitive matching. Default: false",
+                    "default": false
+                },
+                "max_results": {
+                    "type": "integer",
+                    "description": "Maximum matching segments to return (default 50, max 1000)",
+                    "default": 50
+                }
+            },
+            "required": ["query"]
+        })
+    }
+
+    async fn execute(&self, args: serde_json::Value) -> anyhow::Result<ToolResult> {
+        let query = args
+            .get("query")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| anyhow::anyhow!("Missing 'query' parameter"))?;
+        if query.is_empty() {
+            return Ok(ToolResult::failure("Empty query is not allowed."));
+        }
+        let use_regex = args
+            .get("regex")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        let case_sensitive = args
+            .get("case_sensitive")
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false);
+        #[allow(clippy::cast_possible_truncation)]
+        let max_results = args
+            .get("max_results")
+            .and_then(serde_json::Value::as_u64)
+            .map_or(DEFAULT_MAX_RESULTS, |v| v as usize)
+            .min(MAX_RESULTS);
+
+        if self.security.is_rate_limited() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: too many actions in the last hour",
+            ));
+        }
+
+        let matcher = match Self::build_matcher(query, use_regex, case_sensitive) {
+            Ok(matcher) => matcher,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        let value = match transcript::load_transcript_arg(&self.security, &args).await {
+            Ok(value) => value,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+        let segments = match transcript::parse_segments(&value) {
+            Ok(segments) => segments,
+            Err(error) => return Ok(ToolResult::failure(error)),
+        };
+
+        if !self.security.record_action() {
+            return Ok(ToolResult::failure(
+                "Rate limit exceeded: action budget exhausted",
+            ));
+        }
+
+        let matching: Vec<(usize, &TranscriptSegment)> = segments
+            .iter()
+            .enumerate()
+            .filter(|(_, segment)| matcher.is_match(&segment.text))
+            .collect();
+        let matches: Vec<serde_json::Value> = matching
+            .iter()
+            .take(max_results)
+            .map(|(index, segment)| {
+                json!({
+                    "index": index,
+                    "start": segment.start,
+                    "end": segment.end,
+                    "timestamp": transcript::format_timestamp(segment.start, '.'),
+                    "text": segment.text,
+                })
+            })
+            .collect();
+
+        let output = json!({
+            "query": query,
+            "total_segments": segments.len(),
+            "total_matches": matching.len(),
+            "truncated": matching.len() > matches.len(),
+            "matches": matches,
+        });
+
+        Ok(ToolResult {
+            success: true,
+            output: serde_json::to_string_pretty(&output).unwrap_or_default(),
+            error: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::security::AutonomyLevel;
+    use std::path::PathBuf;
+
+    fn test_security(workspace: PathBuf) -> Arc<SecurityPolicy> {
+        Arc::new(SecurityPolicy {
+            autonomy: AutonomyLevel::Supervised,
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        })
+    }
+
+    fn sample_transcript() -> serde_json::Value {
+        json!({
+            "text": "full text",
+            "segments": [
+                {"start": 0.0, "end": 4.2, "text": " Welcome to the show."},
+                {"start": 4.2, "end": 9.0, "text": " Today we talk about Rust."},
+                {"start": 3725.5, "end": 3730.0, "text": " rust is memory safe."}
+            ]
+        })
+    }
+
+    async fn run(tool: &TranscriptSearchTool, args: serde_json::Value) -> serde_json::Value {
+        let result = tool.execute(args).await.unwrap();
+        assert!(result.success, "{:?}", result.error);
+        serde_json::from_str(&result.output).unwrap()
+    }
+
+    #[tokio::test]
+    async fn finds_case_insensitive_literal_matches_with_timestamps() {
+        let tool = TranscriptSearchTool::new(test_security(std::env::temp_dir()));
+        let out = run(
+            &tool,
+            json!({"query": "RUST", "transcript": sample_transcript()}),
+        )
+        .await;
+        assert_eq!(out["total_matches"], 2);
+        assert_eq!(out["matches"][0]["index"], 1);
+        assert_eq!(out["matches"][1]["timestamp"], "01:02:05.500");
+    }
+
+    #[tokio::test]
+    async fn literal_query_escapes_regex_metacharacters() {
+        let tool = TranscriptSearchTool::new(test_security(std::env::temp_dir()));
+        let out = run(
+            &tool,
+            json!({"query": "show.", "transcript": sample_transcript()}),
+        )
+        .await;
+        assert_eq!(out["total_matches"], 1);
+
+        let out = run(
+            &tool,
+            json!({"query": "s.ow", "transcript": sample_transcript()}),
+        )
+        .await;
+        assert_eq!(out["total_matches"], 0);
+    }
+
+    #[tokio::test]
+    async fn regex_and_case_sensitive_modes() {
+        let tool = TranscriptSearchTool::new(test_security(std::env::temp_dir()));
+        let out = run(
+            &tool,
+            json!({
+                "query": r"^Rust|\bRust\b",
+                "regex": true,
+                "case_sensitive": true,
+                "transcript": sample_transcript()
+            }),
+        )
+        .await;
+        assert_eq!(out["total_matches"], 1);
+    }
+
+    #[tokio::test]
+    async fn reads_transcript_from_workspace_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        tokio::fs::write(
+            tmp.path().join("talk.json"),
+            sample_transcript().to_string(),
+        )
+        .await
+        .unwrap();
+        let tool = TranscriptSearchTool::new(test_security(tmp.path().to_path_buf()));
+        let out = run(&tool, json!({"query": "welcome", "path": "talk.json"})).await;
+        assert_eq!(out["total_matches"], 1);
+        assert_eq!(out["total_segments"], 3);
+    }
+
+    #[tokio::test]
+    async fn rejects_invalid_regex() {
+        let tool = TranscriptSearchTool::new(test_security(std::env::temp_dir()));
+        let result = tool
+            .execute(json!({"query": "(", "regex": true, "transcript": []}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert!(result.error.unwrap().contains("Invalid regex"));
+    }
+
+    #[tokio::test]
+    async fn blocks_path_outside_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let tool = TranscriptSearchTool::new(test_security(tmp.path().to_path_buf()));
+        let result = tool
+            .execute(json!({"query": "root", "path": "/etc/passwd"}))
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+}
@@ -0,0 +1,85 @@
+//! Resolution of tool arguments that name an existing file in the workspace.
+
+use crate::security::SecurityPolicy;
+use std::path::{Path, PathBuf};
+
+/// Resolve `path_str` (absolute or relative to the workspace) to an existing
+/// file the security policy allows.
+///
+/// The path is checked both as given and after canonicalization, so symlinks
+/// cannot point a tool outside the workspace.
+pub fn resolve_workspace_file(
+    security: &SecurityPolicy,
+    path_str: &str,
+) -> Result<PathBuf, String> {
+    if !security.is_path_allowed(path_str) {
+        return Err(format!(
+            "Path not allowed: {path_str} (must be within workspace)"
+        ));
+    }
+
+    let raw_path = Path::new(path_str);
+    let candidate = if raw_path.is_absolute() {
+        raw_path.to_path_buf()
+    } else {
+        security.workspace_dir.join(raw_path)
+    };
+
+    let resolved = candidate
+        .canonicalize()
+        .map_err(|_| format!("File not found: {path_str}"))?;
+
+    if !security.is_resolved_path_allowed(&resolved) {
+        return Err(security.resolved_path_violation_message(&resolved));
+    }
+    if !resolved.is_file() {
+        return Err(format!("Not a file: {}", resolved.display()));
+    }
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_security(workspace: PathBuf) -> SecurityPolicy {
+        SecurityPolicy {
+            workspace_dir: workspace,
+            ..SecurityPolicy::default()
+        }
+    }
+
+    #[test]
+    fn resolves_relative_path_inside_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("a.srt"), "x").unwrap();
+        let security = test_security(tmp.path().to_path_buf());
+
+        let resolved = resolve_workspace_file(&security, "a.srt").unwrap();
+        assert_eq!(resolved, tmp.path().canonicalize().unwrap().join("a.srt"));
+    }
+
+    #[test]
+    fn rejects_missing_files_and_directories() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir(tmp.path().join("sub")).unwrap();
+        let security = test_security(tmp.path().to_path_buf());
+
+        assert!(resolve_workspace_file(&security, "nope.srt")
+            .unwrap_err()
+            .contains("File not found"));
+        assert!(resolve_workspace_file(&security, "sub")
+            .unwrap_err()
+            .contains("Not a file"));
+    }
+
+    #[test]
+    fn rejects_paths_outside_workspace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let security = test_security(tmp.path().to_path_buf());
+
+        assert!(resolve_workspace_file(&security, "/etc/passwd").is_err());
+        assert!(resolve_workspace_file(&security, "../outside.srt").is_err());
+    }
+}
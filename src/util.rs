@@ -59,6 +59,22 @@ pub fn floor_utf8_char_boundary(s: &str, index: usize) -> usize {
     i
 }
 
+/// Keep only the last `max_bytes` of `s`, prefixing a marker when anything was dropped.
+///
+/// Used for subprocess stderr, where the useful error is usually at the end and
+/// the head (banners, format tables, tracebacks) can be arbitrarily large.
+pub fn tail_with_truncation_marker(s: &str, max_bytes: usize) -> String {
+    if s.len() <= max_bytes {
+        return s.to_string();
+    }
+
+    let mut start = s.len() - max_bytes;
+    while start < s.len() && !s.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("...[truncated {start} bytes]\n{}", &s[start..])
+}
+
 /// Utility enum for handling optional values.
 pub enum MaybeSet<T> {
     Set(T),
@@ -175,4 +191,28 @@ mod tests {
         // Index 5 is inside "你" (3-byte char), floor should move back to 3.
         assert_eq!(floor_utf8_char_boundary(s, 5), 3);
     }
+
+    #[test]
+    fn test_tail_with_truncation_marker_keeps_short_input() {
+        assert_eq!(
+            tail_with_truncation_marker("error: boom", 64),
+            "error: boom"
+        );
+    }
+
+    #[test]
+    fn test_tail_with_truncation_marker_caps_huge_stderr() {
+        let stderr = format!("{}\nfatal: the real error", "noise line\n".repeat(100_000));
+        let capped = tail_with_truncation_marker(&stderr, 8 * 1024);
+        assert!(capped.starts_with("...[truncated "));
+        assert!(capped.ends_with("fatal: the real error"));
+        assert!(capped.len() < 8 * 1024 + 64);
+    }
+
+    #[test]
+    fn test_tail_with_truncation_marker_respects_char_boundaries() {
+        let capped = tail_with_truncation_marker("😀😀😀", 5);
+        assert!(capped.ends_with("😀"));
+        assert!(capped.starts_with("...[truncated 8 bytes]"));
+    }
 }